@@ -1,5 +1,83 @@
-import { Link } from "react-router-dom";
+import { useEffect, useState } from "react";
+import { Link, useNavigate } from "react-router-dom";
 import short from "short-uuid";
+import { RoomListing } from "@/types/RoomListing";
+import { useSocket } from "@/hooks/useSocket";
+
+function OpenRooms() {
+  const { socket, isConnected, connect, disconnect } = useSocket();
+  const [rooms, setRooms] = useState<RoomListing[]>([]);
+
+  useEffect(() => {
+    connect();
+    return () => {
+      disconnect();
+    };
+  }, []);
+
+  useEffect(() => {
+    if (socket && isConnected) {
+      socket.on("rooms", setRooms);
+      socket.emit("watch_rooms");
+      return () => {
+        socket.off("rooms", setRooms);
+      };
+    }
+  }, [socket, isConnected]);
+
+  if (rooms.length == 0) {
+    return null;
+  }
+  return (
+    <div className="mx-auto max-w-screen-sm bg-white rounded-xl p-10 my-2">
+      <h2 className="text-4xl mb-5">Open Rooms</h2>
+      <ul>
+        {rooms.map((room) => (
+          <li key={room.id} className="flex justify-between my-2">
+            <Link to={`/room/${room.code}`} className="text-blue-500 underline">
+              {room.locked && "🔒 "}
+              {room.name || "Unnamed room"}
+            </Link>
+            <span className="text-gray-500">
+              {room.language} · {room.players} players ·{" "}
+              {room.phase == "Lobby" ? "waiting" : "playing"}
+            </span>
+          </li>
+        ))}
+      </ul>
+    </div>
+  );
+}
+
+function JoinByCode() {
+  const navigate = useNavigate();
+  const [code, setCode] = useState("");
+  function join(e: React.FormEvent<HTMLFormElement>) {
+    e.preventDefault();
+    if (code.trim()) {
+      navigate(`/room/${code.trim().toUpperCase()}`);
+    }
+  }
+  return (
+    <form onSubmit={join} className="mx-auto max-w-screen-sm flex my-2">
+      <input
+        value={code}
+        onChange={(e) => setCode(e.currentTarget.value)}
+        className="flex-grow rounded-xl p-4 font-mono uppercase tracking-widest"
+        placeholder="Room code"
+        maxLength={5}
+        autoComplete="off"
+      />
+      <button
+        type="submit"
+        className="ml-2 rounded-xl px-6 bg-blue-500 text-white font-bold"
+      >
+        Join
+      </button>
+    </form>
+  );
+}
+
 export default function HomePage() {
   return (
     <div className="text-lg">
@@ -17,6 +95,8 @@ export default function HomePage() {
           New Game 🕹
         </button>
       </Link>
+      <JoinByCode />
+      <OpenRooms />
       <div className="mx-auto max-w-screen-sm bg-white rounded-xl p-10 my-2">
         <h2 className="text-4xl mb-10">How to play?</h2>
         <p>
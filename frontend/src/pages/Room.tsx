@@ -1,29 +1,69 @@
 import { useState, useEffect, useRef, KeyboardEvent, useCallback } from "react";
 import { Socket } from "socket.io-client";
-import short from "short-uuid";
 import { Player } from "@/types/Player";
 import { Game as GameState } from "@/types/Game";
 import { InGameState } from "@/types/InGameState";
 import { Point } from "@/types/Point";
 import { NextTurnEvent } from "@/types/NextTurnEvent";
 import { DrawEvent } from "@/types/DrawEvent";
+import { DrawStartEvent } from "@/types/DrawStartEvent";
+import { StrokeStyle } from "@/types/StrokeStyle";
+import { EncodedPoints } from "@/types/EncodedPoints";
 import { VotesTotalEvent } from "@/types/VotesTotalEvent";
+import { RerollEvent } from "@/types/RerollEvent";
 import { VoteFakeEvent } from "@/types/VoteFakeEvent";
+import { ReplayEvent } from "@/types/ReplayEvent";
+import { ReplayDrawEvent } from "@/types/ReplayDrawEvent";
 import { LobbyState } from "@/types/LobbyState";
 import { JoinEvent } from "@/types/JoinEvent";
-import { v5 as uuidv5 } from "uuid";
+import { JoinRequest } from "@/types/JoinRequest";
+import { ErrorEvent } from "@/types/ErrorEvent";
 import { ChatMessage } from "@/types/ChatMessage";
 import { Curve } from "@/types/Curve";
-import { useParams } from "react-router-dom";
+import { useNavigate, useParams } from "react-router-dom";
 import { SocketProvider } from "@/contexts/SocketContext";
 import { useSocket } from "@/hooks/useSocket";
-const translator = short();
+const QUANTIZATION = 4096;
+const DRAW_TICK_MS = 50;
+
+function encodePoints(points: Point[]): EncodedPoints {
+  const deltas: number[] = [];
+  let lastX = 0;
+  let lastY = 0;
+  points.forEach((point) => {
+    const x = Math.round(point.x * QUANTIZATION);
+    const y = Math.round(point.y * QUANTIZATION);
+    deltas.push(x - lastX, y - lastY);
+    lastX = x;
+    lastY = y;
+  });
+  return { deltas };
+}
+
+function decodePoints(encoded: EncodedPoints): Point[] {
+  const points: Point[] = [];
+  let x = 0;
+  let y = 0;
+  for (let i = 0; i + 1 < encoded.deltas.length; i += 2) {
+    x += encoded.deltas[i];
+    y += encoded.deltas[i + 1];
+    points.push({ x: x / QUANTIZATION, y: y / QUANTIZATION, t: 0 });
+  }
+  return points;
+}
 
 function Room() {
   const params = useParams<{ roomId: string }>();
+  const navigate = useNavigate();
   const { socket, isConnected, connect, disconnect } = useSocket();
   const [currentPlayerId, setCurrentPlayerId] = useState<string>();
   const [gameState, setGameState] = useState<GameState>();
+  const [colors, setColors] = useState<string[]>([]);
+  const [roomCode, setRoomCode] = useState<string>();
+  const [chat, setChat] = useState<ChatMessage[]>([]);
+  const joinedSocketIdRef = useRef<string>();
+  const passcodeRef = useRef<string | null>(null);
+  const resumeTokenRef = useRef<string | null>(null);
   function changeState(state: GameState) {
     setGameState(state);
   }
@@ -39,16 +79,35 @@ function Room() {
     if (socket && isConnected && params.roomId) {
       function join() {
         const urlShortUuid = params.roomId;
-        if (urlShortUuid && !currentPlayerId) {
-          const uuid = translator.toUUID(urlShortUuid);
-          socket?.emitWithAck("join", uuid);
+        // Join once per connection; after a reconnect, ask for our old player
+        // back in case the server restored the room
+        if (urlShortUuid && joinedSocketIdRef.current != socket?.id) {
+          joinedSocketIdRef.current = socket?.id;
+          const request: JoinRequest = {
+            room_id: urlShortUuid,
+            resume_token: resumeTokenRef.current,
+            passcode: passcodeRef.current,
+          };
+          socket?.emitWithAck("join", request);
         }
       }
       function onJoin(event: JoinEvent) {
+        if (event.resume_token) {
+          resumeTokenRef.current = event.resume_token;
+        }
         if (event.current_player_id && event.game_state) {
           setCurrentPlayerId(event.current_player_id);
           setGameState(event.game_state);
         }
+        if (event.colors) {
+          setColors(event.colors);
+        }
+        if (event.room_code) {
+          setRoomCode(event.room_code);
+        }
+        if (event.chat) {
+          setChat(event.chat);
+        }
         if (gameState && event.players) {
           setGameState({
             ...gameState,
@@ -63,13 +122,47 @@ function Room() {
         }
       }
 
+      function onGameError(event: ErrorEvent) {
+        // Before we're in the room, errors are about the join itself,
+        // most likely a missing or wrong passcode
+        if (!gameState) {
+          const passcode = prompt(`${event.message}\n\nRoom passcode:`);
+          if (passcode !== null) {
+            passcodeRef.current = passcode;
+            joinedSocketIdRef.current = undefined;
+            join();
+          }
+          return;
+        }
+        alert(event.message);
+      }
+
+      function onRoomClosed() {
+        alert("This room has been closed");
+        navigate("/");
+      }
+
+      function onChatMsg(msg: ChatMessage) {
+        setChat((chat) => [...chat, msg]);
+      }
+
       socket.on("join", onJoin);
       socket.on("start_game", changeState);
+      socket.on("rematch", changeState);
+      socket.on("lobby", changeState);
+      socket.on("game_error", onGameError);
+      socket.on("room_closed", onRoomClosed);
+      socket.on("chat_msg", onChatMsg);
       join();
 
       return () => {
         socket.off("join", onJoin);
         socket.off("start_game", changeState);
+        socket.off("rematch", changeState);
+        socket.off("lobby", changeState);
+        socket.off("game_error", onGameError);
+        socket.off("room_closed", onRoomClosed);
+        socket.off("chat_msg", onChatMsg);
       };
     }
   }, [socket, isConnected, gameState, params.roomId, currentPlayerId]);
@@ -81,7 +174,13 @@ function Room() {
           A Fake Artist goes to New York
         </h1>
         {socket && gameState && gameState.state == "Lobby" ? (
-          <Lobby socket={socket} lobby={gameState} />
+          <Lobby
+            socket={socket}
+            lobby={gameState}
+            colors={colors}
+            roomCode={roomCode}
+            chat={chat}
+          />
         ) : socket &&
           gameState &&
           gameState.state == "InGame" &&
@@ -91,11 +190,17 @@ function Room() {
             initialState={gameState}
             currentPlayerId={currentPlayerId}
             onChangeState={changeState}
+            chat={chat}
           />
-        ) : gameState && gameState.state == "GameOver" && currentPlayerId ? (
+        ) : socket &&
+          gameState &&
+          gameState.state == "GameOver" &&
+          currentPlayerId ? (
           <GameOver
+            socket={socket}
             initialState={gameState}
             currentPlayerId={currentPlayerId}
+            chat={chat}
           />
         ) : (
           <div></div>
@@ -108,19 +213,34 @@ function Room() {
 type LobbyParams = {
   socket: Socket;
   lobby: { state: "Lobby" } & LobbyState;
+  colors: string[];
+  roomCode?: string;
+  chat: ChatMessage[];
 };
-function Lobby({ socket, lobby }: LobbyParams) {
+function Lobby({ socket, lobby, colors, roomCode, chat }: LobbyParams) {
   function startGame() {
     socket.emit("start_game", {});
   }
   function changeName(e: React.FormEvent<HTMLInputElement>) {
     socket.emit("change_name", e.currentTarget.value);
   }
+  function changeColor(color: string) {
+    socket.emit("change_color", color);
+  }
+  const takenColors = lobby.players.map((player) => player.color);
   return (
     <div className="m-2">
       <div className="text-center mx-auto max-w-screen-sm bg-white rounded-xl p-5 mb-5">
         <p>Share this link to let other players join:</p>
         <p>{window.location.href}</p>
+        {roomCode && (
+          <p className="mt-2">
+            Or enter the room code{" "}
+            <span className="font-mono font-bold tracking-widest">
+              {roomCode}
+            </span>
+          </p>
+        )}
       </div>
       <div className="mx-auto max-w-screen-sm bg-white rounded-xl p-10">
         <input
@@ -129,8 +249,20 @@ function Lobby({ socket, lobby }: LobbyParams) {
           type="text"
           name="name"
           placeholder="Enter your name"
+          maxLength={32}
           autoComplete="off"
         />
+        <div className="flex flex-wrap mx-4 mb-4">
+          {colors.map((color) => (
+            <button
+              key={color}
+              onClick={() => changeColor(color)}
+              disabled={takenColors.includes(color)}
+              className="rounded-full w-6 h-6 m-1 disabled:opacity-25"
+              style={{ backgroundColor: color }}
+            ></button>
+          ))}
+        </div>
         <hr />
         <h2 className="text-center text-3xl font-bold my-2 text-gray-800">
           Players:
@@ -152,10 +284,59 @@ function Lobby({ socket, lobby }: LobbyParams) {
           Start Game
         </button>
       </div>
+      <div className="mx-auto max-w-screen-sm bg-white rounded-xl p-2 mt-5">
+        <Chat socket={socket} chat={chat} />
+      </div>
     </div>
   );
 }
 
+type ChatProps = {
+  socket: Socket;
+  chat: ChatMessage[];
+};
+function Chat({ socket, chat }: ChatProps) {
+  function onChatKeyUp(e: KeyboardEvent<HTMLTextAreaElement>) {
+    if (e.key == "Enter" && e.currentTarget.value != "") {
+      e.preventDefault();
+      socket.emit("chat_msg", e.currentTarget.value);
+      e.currentTarget.value = "";
+    }
+  }
+  return (
+    <>
+      <textarea
+        onKeyUp={onChatKeyUp}
+        placeholder="Say something..."
+        maxLength={280}
+        className="p-2 w-full border border-gray-300 rounded-md"
+      ></textarea>
+      <div id="comments" className="w-full max-h-96 overflow-y-auto">
+        {chat
+          .map((message, index) => (
+            <div key={index} className="p-3 border-b border-gray-100">
+              {message.author ? (
+                <>
+                  <div className="whitespace-pre-wrap font-bold text-gray-700">
+                    {message.author.name}
+                  </div>
+                  <div className="whitespace-pre-wrap break-words">
+                    {message.message}
+                  </div>
+                </>
+              ) : (
+                <div className="whitespace-pre-wrap break-words italic text-gray-500">
+                  {message.message}
+                </div>
+              )}
+            </div>
+          ))
+          .reverse()}
+      </div>
+    </>
+  );
+}
+
 type PlayerSlotProps = {
   player: Player | null;
   voters?: Player[];
@@ -204,12 +385,14 @@ type GameProps = {
   initialState: { state: "InGame" } & InGameState;
   currentPlayerId: string;
   onChangeState: (state: GameState) => void;
+  chat: ChatMessage[];
 };
 function Game({
   socket,
   initialState,
   currentPlayerId,
   onChangeState,
+  chat,
 }: GameProps) {
   const [mouseIsDown, setMouseIsDown] = useState(false);
   const [game, setGame] = useState(initialState);
@@ -219,16 +402,19 @@ function Game({
   const lastPositionRef = useRef<Point>();
   const canvasRef = useRef<HTMLCanvasElement>(null);
   const curvesRef = useRef<Curve[]>([]);
+  const pendingPointsRef = useRef<Point[]>([]);
   function normalizePosition(position: Point, canvas: HTMLCanvasElement) {
     return {
       x: position.x / canvas.width,
       y: position.y / canvas.width,
+      t: position.t,
     };
   }
   function denormalizePosition(position: Point, canvas: HTMLCanvasElement) {
     return {
       x: position.x * canvas.width,
       y: position.y * canvas.width,
+      t: position.t,
     };
   }
   function fixDPI(canvas: HTMLCanvasElement) {
@@ -256,16 +442,41 @@ function Game({
         const denormPoint = denormalizePosition(point, canvas);
         context.lineTo(denormPoint.x, denormPoint.y);
       });
-      context.strokeStyle = curve.author.color;
+      applyStyle(context, curve);
       context.stroke();
     });
   }, []);
+  function applyStyle(context: CanvasRenderingContext2D, style: StrokeStyle) {
+    context.strokeStyle = style.color;
+    context.lineWidth = style.width * window.devicePixelRatio;
+    context.lineCap = "round";
+    context.globalAlpha = style.tool == "Highlighter" ? 0.4 : 1;
+  }
   function onMouseDown(_e: React.MouseEvent<HTMLCanvasElement>) {
     if (isPlayerTurn(game)) {
       setMouseIsDown(true);
+      const event: DrawStartEvent = {
+        width: strokeStyle().width,
+        color: null,
+        tool: strokeStyle().tool,
+      };
+      socket.emit("draw_start", event);
     }
   }
 
+  // Points go out in one batch per tick rather than one event per mouse
+  // move, which would run into the server's rate limit on fast displays
+  const flushPoints = useCallback(() => {
+    const points = pendingPointsRef.current;
+    if (points.length > 0) {
+      pendingPointsRef.current = [];
+      socket.emit("draw", encodePoints(points));
+    }
+  }, [socket]);
+  useEffect(() => {
+    const interval = setInterval(flushPoints, DRAW_TICK_MS);
+    return () => clearInterval(interval);
+  }, [flushPoints]);
   function onMouseMove(e: React.MouseEvent<HTMLCanvasElement>) {
     if (mouseIsDown && isPlayerTurn(game)) {
       const canvas = canvasRef.current!;
@@ -276,26 +487,36 @@ function Game({
         {
           x: (e.clientX - rect.left) * scaleX,
           y: (e.clientY - rect.top) * scaleY,
+          t: 0,
         },
         canvas,
       );
-      addPointToCurves(curvesRef.current, turnPlayer(game), normalizedPoint);
-      paintCanvas(canvas, game, normalizedPoint);
-      socket.emit("draw", normalizedPoint);
+      addPointToCurves(
+        curvesRef.current,
+        turnPlayer(game),
+        normalizedPoint,
+        strokeStyle(),
+      );
+      paintCanvas(canvas, normalizedPoint, strokeStyle());
+      pendingPointsRef.current.push(normalizedPoint);
+      if (game.phase == "WordCheck") {
+        setGame({ ...game, phase: "Drawing" });
+      }
     }
   }
   function onMouseUp(_e: React.MouseEvent<HTMLCanvasElement>) {
     if (isPlayerTurn(game)) {
       setMouseIsDown(false);
       lastPositionRef.current = undefined;
+      flushPoints();
       socket.emit("draw_end");
     }
   }
   const paintCanvas = useCallback(
     function paintCanvas(
       canvas: HTMLCanvasElement,
-      game: GameState & { state: "InGame" },
       normalizedPosition: Point,
+      style: StrokeStyle,
     ) {
       const position = denormalizePosition(normalizedPosition, canvas);
       const context = canvas.getContext("2d");
@@ -308,7 +529,7 @@ function Game({
         } else {
           context.moveTo(position.x, position.y);
         }
-        context.strokeStyle = game.players[game.current_player_index].color;
+        applyStyle(context, style);
         context.stroke();
 
         lastPositionRef.current = position;
@@ -316,29 +537,27 @@ function Game({
     },
     [lastPositionRef],
   );
+  function onVoteReroll() {
+    socket.emit("vote_reroll");
+  }
   function onVote(target: Player) {
     const event: VoteFakeEvent = {
       target,
     };
     socket.emit("vote_fake", event);
   }
-  function onChatKeyUp(e: KeyboardEvent<HTMLTextAreaElement>) {
-    if (e.key == "Enter" && e.currentTarget.value != "") {
-      e.preventDefault();
-      socket.emit("chat_msg", e.currentTarget.value);
-      e.currentTarget.value = "";
-    }
-  }
   function addPointToCurves(
     curves: Curve[],
     turnPlayer: Player,
     position: Point,
+    style: StrokeStyle,
   ) {
     const last = curves.length - 1;
     if (curves.length == 0 || curves[last].author.id != turnPlayer.id) {
       curves.push({
         author: turnPlayer,
         points: [position],
+        ...style,
       });
     } else {
       curves[last].points.push(position);
@@ -353,10 +572,19 @@ function Game({
     game.players[game.current_player_index];
   const isPlayerTurn = (game: GameState & { state: "InGame" }) =>
     currentPlayer().id == turnPlayer(game).id;
+  const strokeStyle = (): StrokeStyle => ({
+    width: 2,
+    color: currentPlayer().color,
+    tool: "Pen",
+  });
   useEffect(() => {
     function onNextTurn(event: NextTurnEvent) {
       setPreviousPlayer(game.players[game.current_player_index]);
-      setGame({ ...game, current_player_index: event.current_player_index });
+      // A "leave" may have arrived just before, so build on the latest state
+      setGame((game) => ({
+        ...game,
+        current_player_index: event.current_player_index,
+      }));
       if (event.is_last_turn) {
         setDeliberationModalOpen(true);
       } else {
@@ -369,8 +597,22 @@ function Game({
     }
 
     function onDraw(event: DrawEvent) {
-      addPointToCurves(curvesRef.current, turnPlayer(game), event.position);
-      paintCanvas(canvasRef.current!, game, event.position);
+      decodePoints(event.points).forEach((position) => {
+        addPointToCurves(
+          curvesRef.current,
+          turnPlayer(game),
+          position,
+          event.style,
+        );
+        paintCanvas(canvasRef.current!, position, event.style);
+      });
+      if (game.phase == "WordCheck") {
+        setGame({ ...game, phase: "Drawing" });
+      }
+    }
+
+    function onReroll(event: RerollEvent) {
+      setGame({ ...game, ...event });
     }
 
     function onVoteFake(event: VotesTotalEvent) {
@@ -381,8 +623,20 @@ function Game({
       onChangeState(state);
     }
 
-    function onChatMsg(msg: ChatMessage) {
-      setGame({ ...game, chat: [...game.chat, msg] });
+    function onLeave(player: Player) {
+      setGame((game) => {
+        const index = game.players.findIndex((p) => p.id == player.id);
+        return {
+          ...game,
+          players: game.players.filter((p) => p.id != player.id),
+          spectators: game.spectators.filter((p) => p.id != player.id),
+          // The server follows up with "next_turn" if it was their turn
+          current_player_index:
+            index >= 0 && index < game.current_player_index
+              ? game.current_player_index - 1
+              : game.current_player_index,
+        };
+      });
     }
 
     function onWindowResize() {
@@ -391,16 +645,18 @@ function Game({
 
     socket.on("next_turn", onNextTurn);
     socket.on("draw", onDraw);
+    socket.on("reroll", onReroll);
     socket.on("vote_fake", onVoteFake);
     socket.on("game_over", onGameOver);
-    socket.on("chat_msg", onChatMsg);
+    socket.on("leave", onLeave);
     window.addEventListener("resize", onWindowResize, true);
     return () => {
       socket.off("next_turn", onNextTurn);
       socket.off("draw", onDraw);
+      socket.off("reroll", onReroll);
       socket.off("vote_fake", onVoteFake);
       socket.off("game_over", onGameOver);
-      socket.off("chat_msg", onChatMsg);
+      socket.off("leave", onLeave);
       window.removeEventListener("resize", onWindowResize);
     };
   }, [socket, game, onChangeState, redrawCanvas, paintCanvas]);
@@ -423,6 +679,17 @@ function Game({
             Word: {game.word.text}
           </div>
         )}
+        {!isFakeArtist() &&
+          game.phase == "WordCheck" &&
+          game.rerolls_left > 0 && (
+            <button
+              onClick={onVoteReroll}
+              className="px-4 py-2 rounded-xl bg-blue-500 text-white font-bold m-2"
+            >
+              Don't know this word? Vote to reroll (
+              {game.reroll_votes.length}/{game.players.length - 1})
+            </button>
+          )}
       </div>
       <div className="flex flex-wrap">
         <div className="mr-2">
@@ -471,29 +738,7 @@ function Game({
           />
         </div>
         <div className="bg-white rounded-md m-2 p-2 w-full lg:w-64">
-          <textarea
-            onKeyUp={onChatKeyUp}
-            placeholder="Say something..."
-            className="p-2 w-full border border-gray-300 rounded-md"
-          ></textarea>
-          <div id="comments" className="w-full">
-            {game.chat
-              .slice()
-              .reverse()
-              .map((message) => (
-                <div
-                  key={message.message}
-                  className="p-3 border-b border-gray-100"
-                >
-                  <div className="whitespace-pre-wrap font-bold text-gray-700">
-                    {message.author.name}
-                  </div>
-                  <div className="whitespace-pre-wrap break-words">
-                    {message.message}
-                  </div>
-                </div>
-              ))}
-          </div>
+          <Chat socket={socket} chat={chat} />
         </div>
       </div>
     </div>
@@ -530,28 +775,89 @@ function Deliberation({ game, onVote }: DeliberationProps) {
 }
 
 type GameOverProps = {
+  socket: Socket;
   initialState: { state: "GameOver" } & GameState;
   currentPlayerId: string;
+  chat: ChatMessage[];
 };
-function GameOver({ initialState, currentPlayerId }: GameOverProps) {
+function GameOver({
+  socket,
+  initialState,
+  currentPlayerId,
+  chat,
+}: GameOverProps) {
   const params = useParams<{ roomId: string }>();
   const [game, _setGame] = useState(initialState);
   const [secondsLeft, setSecondsLeft] = useState<number>(10);
-  const redirUrl = `/room/${translator.fromUUID(uuidv5(params.roomId!, uuidv5.URL))}`;
+  const [replaying, setReplaying] = useState(false);
+  const replayCanvasRef = useRef<HTMLCanvasElement>(null);
   function playerIsFakeArtist() {
     return game.fake_artist.id == currentPlayerId;
   }
+  const rematch = useCallback(() => {
+    socket.emit("rematch");
+  }, [socket]);
+  function watchReplay() {
+    const canvas = replayCanvasRef.current!;
+    canvas.getContext("2d")!.clearRect(0, 0, canvas.width, canvas.height);
+    setReplaying(true);
+    const event: ReplayEvent = { speed: 4 };
+    socket.emit("replay", event);
+  }
+  useEffect(() => {
+    let stroke = -1;
+    let lastPosition: Point | undefined;
+    function onReplayDraw(event: ReplayDrawEvent) {
+      const canvas = replayCanvasRef.current;
+      const context = canvas?.getContext("2d");
+      if (!canvas || !context) {
+        return;
+      }
+      if (event.stroke != stroke) {
+        stroke = event.stroke;
+        lastPosition = undefined;
+      }
+      context.strokeStyle = event.style.color;
+      context.lineWidth = (event.style.width * canvas.width) / 1600;
+      context.lineCap = "round";
+      context.globalAlpha = event.style.tool == "Highlighter" ? 0.4 : 1;
+      decodePoints(event.points).forEach((point) => {
+        const position = {
+          x: point.x * canvas.width,
+          y: point.y * canvas.width,
+          t: point.t,
+        };
+        const from = lastPosition ?? position;
+        context.beginPath();
+        context.moveTo(from.x, from.y);
+        context.lineTo(position.x, position.y);
+        context.stroke();
+        lastPosition = position;
+      });
+    }
+    function onReplayEnd() {
+      setReplaying(false);
+    }
+    socket.on("replay_draw", onReplayDraw);
+    socket.on("replay_end", onReplayEnd);
+    return () => {
+      socket.off("replay_draw", onReplayDraw);
+      socket.off("replay_end", onReplayEnd);
+    };
+  }, [socket]);
   useEffect(() => {
-    const cb = () => {
+    if (replaying) {
+      return;
+    }
+    const timeout = setTimeout(() => {
       if (secondsLeft > 0) {
         setSecondsLeft(secondsLeft - 1);
-        setTimeout(cb, 1000);
       } else {
-        window.location.href = redirUrl;
+        rematch();
       }
-    };
-    setTimeout(cb, 1000);
-  }, [secondsLeft, redirUrl]);
+    }, 1000);
+    return () => clearTimeout(timeout);
+  }, [secondsLeft, rematch, replaying]);
   return (
     <div className="max-w-screen-sm p-2 mx-auto">
       <div className="bg-white text-center rounded-xl p-10">
@@ -564,12 +870,37 @@ function GameOver({ initialState, currentPlayerId }: GameOverProps) {
         <div className="text-xl mb-5">
           The fake artist was: <b>{game.fake_artist.name}!</b>
         </div>
+        <canvas
+          ref={replayCanvasRef}
+          width={800}
+          height={450}
+          className="w-full border mb-5"
+        />
+        <div className="mb-5">
+          <button
+            className="text-blue-500 mr-5"
+            onClick={watchReplay}
+            disabled={replaying}
+          >
+            Watch replay
+          </button>
+          <a
+            className="text-blue-500"
+            href={`/room/${params.roomId}/drawing.svg`}
+            download="drawing.svg"
+          >
+            Download the drawing
+          </a>
+        </div>
         <div>
-          <a className="text-blue-500" href={redirUrl}>
+          <button className="text-blue-500" onClick={rematch}>
             Play Again? {secondsLeft}
-          </a>
+          </button>
         </div>
       </div>
+      <div className="bg-white rounded-xl p-2 mt-5">
+        <Chat socket={socket} chat={chat} />
+      </div>
     </div>
   );
 }
@@ -1,4 +1,8 @@
 // This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
 import type { Player } from "./Player";
 
-export type ChatMessage = { author: Player, message: string, };
+/**
+ * A line of chat. Messages without an author come from the server, like
+ * announcing whose turn it is.
+ */
+export type ChatMessage = { author: Player | null, message: string, };
@@ -0,0 +1,7 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+export type CreatedRoom = { id: string, code: string, 
+/**
+ * Link players open to join, relative to the server.
+ */
+path: string, };
@@ -1,5 +1,6 @@
 // This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
 import type { Player } from "./Player";
 import type { Point } from "./Point";
+import type { Tool } from "./Tool";
 
-export type Curve = { points: Array<Point>, author: Player, };
+export type Curve = { points: Array<Point>, author: Player, width: number, color: string, tool: Tool, };
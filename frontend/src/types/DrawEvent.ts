@@ -1,4 +1,5 @@
 // This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
-import type { Point } from "./Point";
+import type { EncodedPoints } from "./EncodedPoints";
+import type { StrokeStyle } from "./StrokeStyle";
 
-export type DrawEvent = { position: Point, };
+export type DrawEvent = { points: EncodedPoints, style: StrokeStyle, };
@@ -0,0 +1,8 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { EncodedPoints } from "./EncodedPoints";
+import type { Point } from "./Point";
+
+/**
+ * Clients may send a single point, a plain batch, or an encoded batch.
+ */
+export type DrawIn = Point | Array<Point> | EncodedPoints;
@@ -0,0 +1,7 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { Tool } from "./Tool";
+
+/**
+ * Sent when the pen goes down; `color` defaults to the player's own color.
+ */
+export type DrawStartEvent = { width: number, color: string | null, tool: Tool, };
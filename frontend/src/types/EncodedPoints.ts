@@ -0,0 +1,9 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * A run of points quantized to the [`QUANTIZATION`] grid and flattened into
+ * `[x0, y0, dx1, dy1, dx2, dy2, ...]`, where the first pair is absolute and
+ * every following pair is the offset from the previous point. Neighbouring
+ * points in a stroke are close together, so the offsets stay short in JSON.
+ */
+export type EncodedPoints = { deltas: Array<number>, };
@@ -1,4 +1,8 @@
 // This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { DrawIn } from "./DrawIn";
+import type { DrawStartEvent } from "./DrawStartEvent";
+import type { JoinIn } from "./JoinIn";
+import type { ReplayEvent } from "./ReplayEvent";
 import type { VoteFakeEvent } from "./VoteFakeEvent";
 
-export type EventIn = { "type": "VoteFake" } & VoteFakeEvent;
+export type EventIn = { "type": "Join" } & JoinIn | { "type": "DrawStart" } & DrawStartEvent | { "type": "Draw" } & DrawIn | { "type": "VoteFake" } & VoteFakeEvent | { "type": "Replay" } & ReplayEvent;
@@ -1,7 +1,11 @@
 // This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
 import type { DrawEvent } from "./DrawEvent";
+import type { ErrorEvent } from "./ErrorEvent";
 import type { JoinEvent } from "./JoinEvent";
 import type { NextTurnEvent } from "./NextTurnEvent";
+import type { ReplayDrawEvent } from "./ReplayDrawEvent";
+import type { RerollEvent } from "./RerollEvent";
+import type { RoomSettings } from "./RoomSettings";
 import type { VotesTotalEvent } from "./VotesTotalEvent";
 
-export type EventOut = { "type": "JoinEvent" } & JoinEvent | { "type": "NextTurn" } & NextTurnEvent | { "type": "DrawEvent" } & DrawEvent | { "type": "VoteFake" } & VotesTotalEvent;
+export type EventOut = { "type": "JoinEvent" } & JoinEvent | { "type": "NextTurn" } & NextTurnEvent | { "type": "DrawEvent" } & DrawEvent | { "type": "VoteFake" } & VotesTotalEvent | { "type": "Reroll" } & RerollEvent | { "type": "Settings" } & RoomSettings | { "type": "ReplayDraw" } & ReplayDrawEvent | { "type": "Error" } & ErrorEvent;
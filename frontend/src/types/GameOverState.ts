@@ -1,5 +1,8 @@
 // This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { ChatMessage } from "./ChatMessage";
+import type { Curve } from "./Curve";
 import type { Player } from "./Player";
 import type { Winner } from "./Winner";
+import type { Word } from "./Word";
 
-export type GameOverState = { players: Array<Player>, winner: Winner, fake_artist: Player, };
+export type GameOverState = { players: Array<Player>, spectators: Array<Player>, curves: Array<Curve>, winner: Winner, fake_artist: Player, word: Word, votes: { [key in string]?: string }, chat: Array<ChatMessage>, };
@@ -0,0 +1,4 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { GameSummary } from "./GameSummary";
+
+export type GamePage = { games: Array<GameSummary>, page: number, page_size: number, total: number, };
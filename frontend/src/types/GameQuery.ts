@@ -0,0 +1,7 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Filters for listing games, newest first. `since` and `until` are seconds
+ * since the Unix epoch, `page` counts from zero.
+ */
+export type GameQuery = { category: string | null, since: number | null, until: number | null, page: number, page_size: number, };
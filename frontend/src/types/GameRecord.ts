@@ -0,0 +1,15 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { ChatMessage } from "./ChatMessage";
+import type { Curve } from "./Curve";
+import type { Player } from "./Player";
+import type { Winner } from "./Winner";
+import type { Word } from "./Word";
+
+/**
+ * Everything worth keeping about a finished game once its room is gone.
+ */
+export type GameRecord = { id: string, room_id: string, 
+/**
+ * Seconds since the Unix epoch.
+ */
+finished_at: number, players: Array<Player>, fake_artist: Player, word: Word, curves: Array<Curve>, votes: { [key in string]?: string }, winner: Winner, chat: Array<ChatMessage>, };
@@ -0,0 +1,9 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { Player } from "./Player";
+import type { Winner } from "./Winner";
+import type { Word } from "./Word";
+
+/**
+ * What the gallery lists for each game, leaving out the drawing and chat.
+ */
+export type GameSummary = { id: string, finished_at: number, players: Array<Player>, fake_artist: Player, word: Word, winner: Winner, };
@@ -1,5 +1,15 @@
 // This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { ChatMessage } from "./ChatMessage";
 import type { Game } from "./Game";
 import type { Player } from "./Player";
+import type { RoomSettings } from "./RoomSettings";
 
-export type JoinEvent = { current_player_id: string | null, game_state: Game | null, players: Array<Player> | null, spectators: Array<Player> | null, };
+export type JoinEvent = { current_player_id: string | null, 
+/**
+ * Sent only to the player who just joined, to keep to themselves.
+ */
+resume_token: string | null, room_code: string | null, game_state: Game | null, settings: RoomSettings | null, colors: Array<string> | null, players: Array<Player> | null, spectators: Array<Player> | null, 
+/**
+ * Recent chat, sent to the player who just joined.
+ */
+chat: Array<ChatMessage> | null, };
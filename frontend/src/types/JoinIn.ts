@@ -0,0 +1,7 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { JoinRequest } from "./JoinRequest";
+
+/**
+ * Older clients send just the room id.
+ */
+export type JoinIn = string | JoinRequest;
@@ -0,0 +1,8 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * `room_id` is the room's UUID, its short link form, or its code.
+ * `resume_token` takes back a player from before a server restart,
+ * `passcode` is needed for rooms that have one.
+ */
+export type JoinRequest = { room_id: string, resume_token: string | null, passcode: string | null, };
@@ -1,4 +1,8 @@
 // This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
 import type { Player } from "./Player";
 
-export type LobbyState = { players: Array<Player>, spectators: Array<Player>, };
+export type LobbyState = { players: Array<Player>, 
+/**
+ * How many words each player has submitted, without revealing them.
+ */
+word_submissions: { [key in string]?: number }, };
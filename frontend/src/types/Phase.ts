@@ -0,0 +1,7 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Before the first stroke the real artists can still swap out a word
+ * nobody recognizes.
+ */
+export type Phase = "WordCheck" | "Drawing";
@@ -1,3 +1,8 @@
 // This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
 
-export type Point = { x: number, y: number, };
+export type Point = { x: number, y: number, 
+/**
+ * Milliseconds since the turn started. Clients may omit it, the server
+ * stamps every point it accepts.
+ */
+t: number, };
@@ -0,0 +1,12 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { ChatMessage } from "./ChatMessage";
+import type { Curve } from "./Curve";
+import type { Player } from "./Player";
+import type { Winner } from "./Winner";
+import type { Word } from "./Word";
+
+/**
+ * A finished game as the gallery shows it, without the room it was played
+ * in and with anonymized player ids.
+ */
+export type PublicGame = { id: string, finished_at: number, players: Array<Player>, fake_artist: Player, word: Word, curves: Array<Curve>, votes: { [key in string]?: string }, winner: Winner, chat: Array<ChatMessage>, };
@@ -0,0 +1,10 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { EncodedPoints } from "./EncodedPoints";
+import type { StrokeStyle } from "./StrokeStyle";
+
+export type ReplayDrawEvent = { 
+/**
+ * Which stroke the points belong to, counting from zero. Turns ended
+ * without drawing have none, so this isn't the turn number.
+ */
+stroke: number, points: EncodedPoints, style: StrokeStyle, };
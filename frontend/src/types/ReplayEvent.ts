@@ -0,0 +1,6 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+
+/**
+ * Asks for the finished game's strokes; `speed` defaults to real time.
+ */
+export type ReplayEvent = { speed: number | null, };
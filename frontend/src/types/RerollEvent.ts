@@ -0,0 +1,4 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { Word } from "./Word";
+
+export type RerollEvent = { word: Word, reroll_votes: Array<string>, rerolls_left: number, };
@@ -0,0 +1,7 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { RoomPhase } from "./RoomPhase";
+
+/**
+ * A public room as shown in the room browser.
+ */
+export type RoomListing = { id: string, code: string, name: string, language: string, phase: RoomPhase, players: number, locked: boolean, };
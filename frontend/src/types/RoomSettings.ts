@@ -0,0 +1,36 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { WordFilter } from "./WordFilter";
+
+/**
+ * Options chosen in the lobby that apply to every game played in a room.
+ */
+export type RoomSettings = { word_filter: WordFilter, 
+/**
+ * How many times per game the real artists may vote to swap the word.
+ */
+max_rerolls: number, 
+/**
+ * When non-zero, every player submits this many words in the lobby and
+ * the game draws from them instead of the built-in list.
+ */
+words_per_player: number, 
+/**
+ * Finished games are saved to the public gallery unless a room opts out.
+ */
+keep_history: boolean, 
+/**
+ * Public rooms are listed in the room browser for anyone to join.
+ */
+public: boolean, 
+/**
+ * What the room browser calls the room.
+ */
+name: string, 
+/**
+ * The language players are expected to chat in, as a code like "en".
+ */
+language: string, 
+/**
+ * When set, joining the room takes this passcode.
+ */
+passcode: string | null, };
@@ -0,0 +1,11 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { RoomPhase } from "./RoomPhase";
+
+/**
+ * What anyone may see about a room without joining it.
+ */
+export type RoomStatus = { id: string, code: string, phase: RoomPhase, players: number, spectators: number, round: number | null, max_rounds: number | null, 
+/**
+ * Whether joining takes a passcode.
+ */
+locked: boolean, };
@@ -1,3 +1,4 @@
 // This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { Difficulty } from "./Difficulty";
 
-export type Word = { category: string, text: string, };
+export type Word = { category: string, text: string, difficulty: Difficulty, };
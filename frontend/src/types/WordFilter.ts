@@ -0,0 +1,8 @@
+// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.
+import type { Difficulty } from "./Difficulty";
+
+/**
+ * Which words a room is willing to play with. An empty `categories` list
+ * allows every category not listed in `excluded_categories`.
+ */
+export type WordFilter = { categories: Array<string>, excluded_categories: Array<string>, max_difficulty: Difficulty | null, };
@@ -0,0 +1,34 @@
+use std::{collections::BTreeMap, sync::Mutex};
+
+use socketioxide::SocketIo;
+use uuid::Uuid;
+
+use crate::game_server::RoomListing;
+
+/// Socket.io room of the sockets watching the room browser.
+pub const WATCHERS: &str = "room_browser";
+
+/// Public rooms as last reported by their actors.
+static LISTINGS: Mutex<BTreeMap<Uuid, RoomListing>> = Mutex::new(BTreeMap::new());
+
+pub fn listings() -> Vec<RoomListing> {
+    LISTINGS.lock().unwrap().values().cloned().collect()
+}
+
+/// Records how a room should appear in the browser, or that it shouldn't,
+/// sending the new list to watchers when anything changed.
+pub fn update(io: &SocketIo, id: Uuid, listing: Option<RoomListing>) {
+    let listings = {
+        let mut listings = LISTINGS.lock().unwrap();
+        if listings.get(&id) == listing.as_ref() {
+            return;
+        }
+        match listing {
+            Some(listing) => listings.insert(id, listing),
+            None => listings.remove(&id),
+        };
+        listings.values().cloned().collect::<Vec<_>>()
+    };
+    // Wrapped so the list arrives as one argument rather than one per room
+    io.to(WATCHERS).emit("rooms", [listings]).ok();
+}
@@ -0,0 +1,141 @@
+use std::{collections::HashMap, env, fs, str::FromStr, sync::OnceLock};
+
+use itertools::Itertools;
+use unicode_normalization::UnicodeNormalization;
+
+/// Server-wide settings read once from the environment.
+pub struct Config {
+    /// Maximum distance, in canvas widths, a stored point may be moved by
+    /// curve simplification. Zero keeps every point.
+    pub simplify_tolerance: f32,
+    /// SQLite file finished games are stored in.
+    #[cfg_attr(not(feature = "sqlite"), allow(dead_code))]
+    pub database_path: String,
+    /// File live rooms are written to on shutdown and restored from on
+    /// startup.
+    pub snapshot_path: String,
+    /// Directory rooms write their event logs to, one per game, as each
+    /// game gives way to a rematch and when the room closes. Logs aren't
+    /// kept when unset.
+    pub log_dir: Option<String>,
+    /// When set, every room's RNG starts from this seed so shuffles, fake
+    /// artist and word picks play out the same way each run.
+    pub seed: Option<u64>,
+    /// Enables creating and closing rooms over the HTTP API, for requests
+    /// sending `Authorization: Bearer <token>`.
+    pub api_token: Option<String>,
+    /// How often one socket may send each event, by event name. Any event
+    /// can be given its own limit as `FAKE_ARTIST_RATE_LIMIT_<EVENT>`, e.g.
+    /// `FAKE_ARTIST_RATE_LIMIT_VOTE_FAKE`, with `_CHAT` and `_NAME` kept for
+    /// `chat_msg` and `change_name`.
+    pub rate_limits: HashMap<String, RateLimit>,
+    /// The limit for events without one of their own, from
+    /// `FAKE_ARTIST_RATE_LIMIT`.
+    pub default_rate_limit: RateLimit,
+    /// Words and phrases masked in chat messages and player names, read
+    /// from the file named by `FAKE_ARTIST_WORD_FILTER`, one per line.
+    pub blocked_words: Vec<String>,
+}
+
+/// A token bucket holding up to `burst` events, refilled at `per_second`.
+/// Written as `burst:per_second` in the environment, e.g. `5:1`.
+#[derive(Debug, Clone, Copy)]
+pub struct RateLimit {
+    pub burst: f32,
+    pub per_second: f32,
+}
+
+impl RateLimit {
+    const fn new(burst: f32, per_second: f32) -> Self {
+        RateLimit { burst, per_second }
+    }
+}
+
+impl FromStr for RateLimit {
+    type Err = ();
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let (burst, per_second) = value.split_once(':').ok_or(())?;
+        let parse = |number: &str| number.trim().parse::<f32>().map_err(|_| ());
+        Ok(RateLimit::new(parse(burst)?, parse(per_second)?))
+    }
+}
+
+impl Config {
+    fn from_env() -> Self {
+        Config {
+            simplify_tolerance: env_or("FAKE_ARTIST_SIMPLIFY_TOLERANCE", 0.001),
+            database_path: env_or("FAKE_ARTIST_DATABASE", "fake-artist.db".to_string()),
+            snapshot_path: env_or("FAKE_ARTIST_SNAPSHOT", "rooms.json".to_string()),
+            log_dir: env::var("FAKE_ARTIST_LOG_DIR").ok(),
+            seed: env::var("FAKE_ARTIST_SEED")
+                .ok()
+                .and_then(|seed| seed.parse().ok()),
+            api_token: env::var("FAKE_ARTIST_API_TOKEN")
+                .ok()
+                .filter(|token| !token.is_empty()),
+            rate_limits: rate_limits_from_env(),
+            default_rate_limit: env_or("FAKE_ARTIST_RATE_LIMIT", RateLimit::new(10.0, 2.0)),
+            blocked_words: env::var("FAKE_ARTIST_WORD_FILTER")
+                .map(|path| read_word_filter(&path))
+                .unwrap_or_default(),
+        }
+    }
+    pub fn rate_limit(&self, event: &str) -> RateLimit {
+        self.rate_limits
+            .get(event)
+            .copied()
+            .unwrap_or(self.default_rate_limit)
+    }
+}
+
+/// The built-in per event limits, overridden by any
+/// `FAKE_ARTIST_RATE_LIMIT_<EVENT>` variable that parses.
+fn rate_limits_from_env() -> HashMap<String, RateLimit> {
+    let mut limits = HashMap::from([
+        ("chat_msg".to_string(), RateLimit::new(5.0, 1.0)),
+        // Names are sent on every keystroke
+        ("change_name".to_string(), RateLimit::new(30.0, 5.0)),
+        // Older clients send one event per pointer move while drawing
+        ("draw".to_string(), RateLimit::new(200.0, 120.0)),
+    ]);
+    for (name, value) in env::vars() {
+        let Some(event) = name.strip_prefix("FAKE_ARTIST_RATE_LIMIT_") else {
+            continue;
+        };
+        let event = match event {
+            "CHAT" => "chat_msg".to_string(),
+            "NAME" => "change_name".to_string(),
+            event => event.to_lowercase(),
+        };
+        if let Ok(limit) = value.parse() {
+            limits.insert(event, limit);
+        }
+    }
+    limits
+}
+
+/// Reads a word filter file, skipping blank lines and `#` comments.
+fn read_word_filter(path: &str) -> Vec<String> {
+    let words = fs::read_to_string(path)
+        .unwrap_or_else(|err| panic!("Failed to read word filter {}: {}", path, err));
+    words
+        .lines()
+        .map(|line| {
+            let line = line.nfkc().collect::<String>().to_lowercase();
+            line.split_whitespace().join(" ")
+        })
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .collect()
+}
+
+fn env_or<T: FromStr>(name: &str, default: T) -> T {
+    env::var(name)
+        .ok()
+        .and_then(|value| value.parse().ok())
+        .unwrap_or(default)
+}
+
+pub fn config() -> &'static Config {
+    static CONFIG: OnceLock<Config> = OnceLock::new();
+    CONFIG.get_or_init(Config::from_env)
+}
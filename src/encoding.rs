@@ -0,0 +1,73 @@
+use serde::{Deserialize, Serialize};
+use ts_rs::TS;
+
+use crate::game::Point;
+
+/// Coordinates are normalized to the canvas width, so this gives a grid of
+/// 4096 steps across, finer than any screen the game is played on.
+const QUANTIZATION: f32 = 4096.0;
+
+/// A run of points quantized to the [`QUANTIZATION`] grid and flattened into
+/// `[x0, y0, dx1, dy1, dx2, dy2, ...]`, where the first pair is absolute and
+/// every following pair is the offset from the previous point. Neighbouring
+/// points in a stroke are close together, so the offsets stay short in JSON.
+#[derive(Debug, Deserialize, Serialize, TS, Clone, Default)]
+pub struct EncodedPoints {
+    pub deltas: Vec<i32>,
+}
+
+impl EncodedPoints {
+    pub fn encode(points: &[Point]) -> Self {
+        let mut deltas = Vec::with_capacity(points.len() * 2);
+        let (mut last_x, mut last_y) = (0, 0);
+        for point in points {
+            let x = (point.x * QUANTIZATION).round() as i32;
+            let y = (point.y * QUANTIZATION).round() as i32;
+            deltas.push(x - last_x);
+            deltas.push(y - last_y);
+            (last_x, last_y) = (x, y);
+        }
+        EncodedPoints { deltas }
+    }
+    /// Returns `None` if the offsets run off the end of `i32`, which no
+    /// honest client sends.
+    pub fn decode(&self) -> Option<Vec<Point>> {
+        let (mut x, mut y) = (0i32, 0i32);
+        self.deltas
+            .chunks_exact(2)
+            .map(|delta| {
+                x = x.checked_add(delta[0])?;
+                y = y.checked_add(delta[1])?;
+                Some(Point {
+                    x: x as f32 / QUANTIZATION,
+                    y: y as f32 / QUANTIZATION,
+                    t: 0,
+                })
+            })
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decodes_what_it_encodes() {
+        let points =
+            [(0.25, 0.5), (0.2505, 0.499), (0.0, 0.5625)].map(|(x, y)| Point { x, y, t: 0 });
+        let decoded = EncodedPoints::encode(&points).decode().unwrap();
+        for (point, decoded) in points.iter().zip(decoded) {
+            assert!((point.x - decoded.x).abs() <= 0.5 / QUANTIZATION);
+            assert!((point.y - decoded.y).abs() <= 0.5 / QUANTIZATION);
+        }
+    }
+
+    #[test]
+    fn rejects_offsets_that_overflow() {
+        let encoded = EncodedPoints {
+            deltas: vec![i32::MAX, 0, 1, 0],
+        };
+        assert!(encoded.decode().is_none());
+    }
+}
@@ -1,22 +1,173 @@
-use crate::lists::{self, Word};
+use crate::config::config;
+use crate::history::GameRecord;
+use crate::lists::{self, Word, WordDeck, WordFilter};
+use crate::moderation;
+use crate::settings::{RoomSettings, MAX_PASSCODE_LENGTH, MAX_ROOM_NAME_LENGTH};
+use crate::simplify::simplify;
 use itertools::Itertools;
 use rand::seq::IteratorRandom;
 use rand::seq::SliceRandom;
+use rand::Rng;
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use std::cmp::Reverse;
+use std::collections::{BTreeMap, HashMap};
+use std::fmt;
+use std::time::{SystemTime, UNIX_EPOCH};
 use ts_rs::TS;
 use uuid::Uuid;
 
+#[derive(Debug)]
+pub enum GameError {
+    NotInLobby,
+    NotInGame,
+    NoWordsAvailable,
+    DrawingStarted,
+    NoRerollsLeft,
+    CannotVoteReroll,
+    WordPoolDisabled,
+    WrongWordCount(u8),
+    InvalidWord,
+    WordsMissing,
+    InvalidColor,
+    ColorTaken,
+    NotYourTurn,
+    DrawingFinished,
+    InvalidStroke,
+    InvalidPoint,
+    CurveTooLong,
+    DrawingTooLarge,
+    GameNotOver,
+    InvalidRoomName,
+    NotHost,
+    InvalidLanguage,
+    InvalidPasscode,
+    WrongPasscode,
+    TooManyAttempts,
+    UnknownRoom,
+    RateLimited,
+}
+
+impl fmt::Display for GameError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            GameError::NotInLobby => write!(f, "This can only be done in the lobby"),
+            GameError::NotInGame => write!(f, "This can only be done during a game"),
+            GameError::NoWordsAvailable => {
+                write!(f, "No words match the selected categories and difficulty")
+            }
+            GameError::DrawingStarted => {
+                write!(f, "The word can't be changed once drawing has started")
+            }
+            GameError::NoRerollsLeft => write!(f, "No word rerolls left for this game"),
+            GameError::CannotVoteReroll => write!(f, "Only real artists can vote to reroll"),
+            GameError::WordPoolDisabled => {
+                write!(f, "This room doesn't use player-submitted words")
+            }
+            GameError::WrongWordCount(count) => write!(f, "Each player must submit {count} words"),
+            GameError::InvalidWord => write!(
+                f,
+                "Words and categories must be between 1 and {MAX_SUBMITTED_WORD_LENGTH} characters"
+            ),
+            GameError::WordsMissing => write!(f, "Not every player has submitted their words yet"),
+            GameError::InvalidColor => write!(f, "That color isn't in the palette"),
+            GameError::ColorTaken => write!(f, "Someone else is already using that color"),
+            GameError::NotYourTurn => write!(f, "It's not your turn to draw"),
+            GameError::DrawingFinished => write!(f, "The drawing is finished, it's time to vote"),
+            GameError::InvalidPoint => write!(f, "Points must lie within the canvas"),
+            GameError::CurveTooLong => write!(
+                f,
+                "Strokes can't have more than {MAX_POINTS_PER_CURVE} points"
+            ),
+            GameError::DrawingTooLarge => write!(
+                f,
+                "The drawing can't have more than {MAX_POINTS_PER_GAME} points"
+            ),
+            GameError::GameNotOver => write!(f, "Replays are available once the game is over"),
+            GameError::InvalidRoomName => write!(
+                f,
+                "Room names can't be longer than {MAX_ROOM_NAME_LENGTH} characters"
+            ),
+            GameError::NotHost => write!(f, "Only the room's host can change its settings"),
+            GameError::InvalidLanguage => {
+                write!(f, "Languages must be given as a code such as \"en\" or \"pt-BR\"")
+            }
+            GameError::InvalidPasscode => write!(
+                f,
+                "Passcodes must be between 1 and {MAX_PASSCODE_LENGTH} characters"
+            ),
+            GameError::WrongPasscode => write!(f, "Wrong passcode for this room"),
+            GameError::TooManyAttempts => {
+                write!(f, "Too many wrong passcodes, try again in a minute")
+            }
+            GameError::UnknownRoom => write!(f, "There's no room with that code"),
+            GameError::RateLimited => write!(f, "You're doing that too often, slow down"),
+            GameError::InvalidStroke => write!(
+                f,
+                "Strokes must use your own color and a width between {MIN_STROKE_WIDTH} and {MAX_STROKE_WIDTH}"
+            ),
+        }
+    }
+}
+
 #[derive(Debug, Deserialize, Serialize, TS, Clone)]
 pub struct Point {
-    x: f32,
-    y: f32,
+    pub x: f32,
+    pub y: f32,
+    /// Milliseconds since the turn started. Clients may omit it, the server
+    /// stamps every point it accepts.
+    #[serde(default)]
+    pub t: u32,
+}
+
+/// The canvas is 16:9 and points are normalized to its width, so `y` only
+/// goes up to this.
+pub const CANVAS_HEIGHT: f32 = 9.0 / 16.0;
+
+impl Point {
+    /// Anything outside the canvas (or not a number at all) can't have come
+    /// from it.
+    fn is_valid(&self) -> bool {
+        (0.0..=1.0).contains(&self.x) && (0.0..=CANVAS_HEIGHT).contains(&self.y)
+    }
 }
 
-#[derive(Debug, Serialize, TS, Clone)]
+#[derive(Debug, Deserialize, Serialize, TS, Clone)]
 pub struct Curve {
-    points: Vec<Point>,
-    author: Player,
+    pub points: Vec<Point>,
+    pub author: Player,
+    #[serde(flatten)]
+    pub style: StrokeStyle,
+}
+
+const MAX_POINTS_PER_CURVE: usize = 5_000;
+const MAX_POINTS_PER_GAME: usize = 100_000;
+const MIN_STROKE_WIDTH: f32 = 1.0;
+const MAX_STROKE_WIDTH: f32 = 24.0;
+const DEFAULT_STROKE_WIDTH: f32 = 2.0;
+
+#[derive(Debug, Deserialize, Serialize, TS, Clone, Copy, Default, PartialEq)]
+pub enum Tool {
+    #[default]
+    Pen,
+    Marker,
+    Highlighter,
+}
+
+#[derive(Debug, Deserialize, Serialize, TS, Clone, PartialEq)]
+pub struct StrokeStyle {
+    pub width: f32,
+    pub color: String,
+    pub tool: Tool,
+}
+
+impl StrokeStyle {
+    fn default_for(player: &Player) -> Self {
+        StrokeStyle {
+            width: DEFAULT_STROKE_WIDTH,
+            color: player.color.clone(),
+            tool: Tool::default(),
+        }
+    }
 }
 
 #[derive(Debug, Clone, Deserialize, Serialize, TS)]
@@ -27,79 +178,187 @@ pub struct Player {
 }
 
 impl Player {
-    pub fn random() -> Self {
+    /// Creates a player whose name and color differ from everyone in `others`.
+    pub fn random(others: &[Player], rng: &mut impl Rng) -> Self {
+        let names = others
+            .iter()
+            .map(|player| player.name.as_str())
+            .collect_vec();
+        let colors = others
+            .iter()
+            .map(|player| player.color.as_str())
+            .collect_vec();
         Player {
-            id: Uuid::new_v4(),
-            name: lists::random_artist().name.to_string(),
-            color: lists::random_color().to_string(),
+            id: uuid::Builder::from_random_bytes(rng.gen()).into_uuid(),
+            name: lists::random_artist(&names, rng),
+            color: lists::random_color(&colors, rng).to_string(),
         }
     }
 }
 
+#[allow(dead_code)]
 #[derive(Debug, Deserialize, Serialize, TS)]
 pub struct Vote {
     author: Player,
     target: Player,
 }
 
-#[derive(Debug, Serialize, TS, Clone)]
+/// A line of chat. Messages without an author come from the server, like
+/// announcing whose turn it is.
+#[derive(Debug, Deserialize, Serialize, TS, Clone)]
 pub struct ChatMessage {
-    author: Player,
+    author: Option<Player>,
     message: String,
 }
 impl ChatMessage {
     pub fn new(author: Player, message: &str) -> Self {
         Self {
-            author,
+            author: Some(author),
             message: message.to_string(),
         }
     }
+    pub fn system(message: String) -> Self {
+        Self {
+            author: None,
+            message,
+        }
+    }
+    pub fn author_mut(&mut self) -> Option<&mut Player> {
+        self.author.as_mut()
+    }
 }
 
-#[derive(Debug, Serialize, TS, Clone)]
+const MAX_SUBMITTED_WORD_LENGTH: usize = 40;
+
+#[derive(Debug, Deserialize, Serialize, TS, Clone)]
 pub struct LobbyState {
     players: Vec<Player>,
+    /// How many words each player has submitted, without revealing them.
+    word_submissions: HashMap<Uuid, usize>,
+    #[serde(skip)]
+    #[ts(skip)]
+    word_pool: HashMap<Uuid, Vec<Word>>,
 }
 
 impl LobbyState {
+    fn new(players: Vec<Player>) -> Self {
+        LobbyState {
+            players,
+            word_submissions: HashMap::new(),
+            word_pool: HashMap::new(),
+        }
+    }
     fn players(&self) -> Vec<Player> {
         self.players.clone()
     }
-    pub fn next(&mut self) -> Game {
+    pub fn next(
+        &mut self,
+        words: &mut WordDeck,
+        settings: &RoomSettings,
+        fake_artists: &mut FakeArtistRotation,
+        rng: &mut impl Rng,
+        now: u64,
+    ) -> Result<Game, GameError> {
+        let use_word_pool = settings.words_per_player > 0;
+        if use_word_pool
+            && self.players.iter().any(|player| {
+                self.word_submissions.get(&player.id).copied().unwrap_or(0)
+                    < settings.words_per_player as usize
+            })
+        {
+            return Err(GameError::WordsMissing);
+        }
+        let fake_artist = fake_artists.pick(&self.players, rng);
+        let (word, word_pool) = if use_word_pool {
+            // Never hand the fake artist a word they came up with themselves
+            let mut word_pool = self
+                .players
+                .iter()
+                .filter(|player| player.id != fake_artist.id)
+                .filter_map(|player| self.word_pool.get(&player.id))
+                .flatten()
+                .cloned()
+                .collect_vec();
+            word_pool.shuffle(rng);
+            let word = word_pool.pop().ok_or(GameError::NoWordsAvailable)?;
+            (word, word_pool)
+        } else {
+            let word = words
+                .draw(&settings.word_filter, None, rng)
+                .ok_or(GameError::NoWordsAvailable)?;
+            (word, vec![])
+        };
         let players = &mut self.players;
-        players.shuffle(&mut rand::thread_rng());
+        players.shuffle(rng);
 
-        Game::InGame(InGameState {
+        Ok(Game::InGame(InGameState {
             players: players.clone(),
             current_round: 1,
             max_rounds: 2,
             current_player_index: 0,
             curves: vec![],
             current_curve: None,
-            word: lists::random_word(),
-            fake_artist: self.random_player(),
+            total_points: 0,
+            turn_started: now,
+            word,
+            phase: Phase::WordCheck,
+            reroll_votes: vec![],
+            rerolls_left: settings.max_rerolls,
+            word_pool,
+            fake_artist,
             spectators: vec![],
             chat: vec![],
             votes: HashMap::new(),
-        })
-    }
-    fn random_player(&self) -> Player {
-        self.players
-            .iter()
-            .choose(&mut rand::thread_rng())
-            .unwrap()
-            .clone()
+        }))
     }
     fn add_player(&mut self, player: Player) {
         self.players.push(player)
     }
     fn remove_player(&mut self, player: Player) {
-        let index = self
+        self.players
+            .retain(|player_iter| player_iter.id != player.id);
+        self.word_pool.remove(&player.id);
+        self.word_submissions.remove(&player.id);
+    }
+    fn submit_words(
+        &mut self,
+        player: Player,
+        words: Vec<Word>,
+        words_per_player: u8,
+    ) -> Result<(), GameError> {
+        if words_per_player == 0 {
+            return Err(GameError::WordPoolDisabled);
+        }
+        if words.len() != words_per_player as usize {
+            return Err(GameError::WrongWordCount(words_per_player));
+        }
+        // Everyone gets to see these, as the secret word and its category
+        let words = words
+            .into_iter()
+            .map(|word| Word {
+                category: moderation::clean(&word.category, MAX_SUBMITTED_WORD_LENGTH),
+                text: moderation::clean(&word.text, MAX_SUBMITTED_WORD_LENGTH),
+                ..word
+            })
+            .collect_vec();
+        if words.iter().any(|word| {
+            word.category.is_empty()
+                || word.text.is_empty()
+                || word.category.chars().count() > MAX_SUBMITTED_WORD_LENGTH
+                || word.text.chars().count() > MAX_SUBMITTED_WORD_LENGTH
+        }) {
+            return Err(GameError::InvalidWord);
+        }
+        if !self
             .players
             .iter()
-            .position(|player_iter| player_iter.id == player.id)
-            .unwrap();
-        self.players.remove(index);
+            .any(|player_iter| player_iter.id == player.id)
+        {
+            return Err(GameError::NotInLobby);
+        }
+        self.word_submissions.insert(player.id, words.len());
+        self.word_pool.insert(player.id, words);
+        Ok(())
     }
     fn update_player(&mut self, player: Player) {
         let idx = self
@@ -111,15 +370,64 @@ impl LobbyState {
     }
 }
 
-#[derive(Debug, Serialize, TS, Clone)]
+/// Remembers who has already been the fake artist in a room so the role
+/// goes around everyone before anybody gets it twice.
+#[derive(Debug, Default, Clone, Deserialize, Serialize)]
+pub struct FakeArtistRotation {
+    picked: Vec<Uuid>,
+}
+
+impl FakeArtistRotation {
+    fn pick(&mut self, players: &[Player], rng: &mut impl Rng) -> Player {
+        let mut candidates = players
+            .iter()
+            .filter(|player| !self.picked.contains(&player.id))
+            .collect_vec();
+        if candidates.is_empty() {
+            let last = self.picked.last().copied();
+            self.picked.clear();
+            candidates = players
+                .iter()
+                .filter(|player| players.len() == 1 || Some(player.id) != last)
+                .collect_vec();
+        }
+        let fake_artist = candidates.into_iter().choose(rng).unwrap().clone();
+        self.picked.push(fake_artist.id);
+        fake_artist
+    }
+}
+
+/// Before the first stroke the real artists can still swap out a word
+/// nobody recognizes.
+#[derive(Debug, Deserialize, Serialize, TS, Clone, PartialEq)]
+pub enum Phase {
+    WordCheck,
+    Drawing,
+}
+
+#[derive(Debug, Deserialize, Serialize, TS, Clone)]
 pub struct InGameState {
     players: Vec<Player>,
     pub current_player_index: usize,
     curves: Vec<Curve>,
     current_curve: Option<Curve>,
+    #[serde(skip)]
+    #[ts(skip)]
+    total_points: usize,
+    /// When the current turn began, in milliseconds on the room's clock.
+    #[serde(skip)]
+    #[ts(skip)]
+    turn_started: u64,
     current_round: u8,
     max_rounds: u8,
-    word: Word<'static>,
+    pub word: Word,
+    phase: Phase,
+    pub reroll_votes: Vec<Uuid>,
+    pub rerolls_left: u8,
+    /// Unused player-submitted words, already excluding the fake artist's.
+    #[serde(skip)]
+    #[ts(skip)]
+    word_pool: Vec<Word>,
     fake_artist: Player,
     spectators: Vec<Player>,
     chat: Vec<ChatMessage>,
@@ -127,14 +435,16 @@ pub struct InGameState {
 }
 impl InGameState {
     fn next(&mut self) -> Option<Game> {
-        let (accused_id, _votes) = self
-            .votes
-            .values()
-            .counts()
+        let mut counts = BTreeMap::new();
+        for target in self.votes.values() {
+            *counts.entry(*target).or_insert(0) += 1;
+        }
+        // Ties go to the smallest id, so a rebuilt game ends the same way
+        let (accused_id, _votes) = counts
             .into_iter()
-            .max_by_key(|(_uuid, count)| *count)
+            .max_by_key(|(uuid, count)| (*count, Reverse(*uuid)))
             .unwrap();
-        let winner = if *accused_id == self.fake_artist.id {
+        let winner = if accused_id == self.fake_artist.id {
             Winner::RealArtists
         } else {
             Winner::FakeArtist
@@ -142,8 +452,13 @@ impl InGameState {
         if self.votes.len() >= self.players.len() {
             Some(Game::GameOver(GameOverState {
                 players: self.players.clone(),
+                spectators: self.spectators.clone(),
+                curves: self.curves.clone(),
                 fake_artist: self.fake_artist.clone(),
                 winner,
+                word: self.word.clone(),
+                votes: self.votes.clone(),
+                chat: self.chat.clone(),
             }))
         } else {
             None
@@ -158,44 +473,130 @@ impl InGameState {
     fn add_player(&mut self, player: Player) {
         self.spectators.push(player)
     }
-    fn remove_player(&mut self, player: Player) {
+    /// Takes the player out of the game, handing the turn to whoever is next
+    /// if it was theirs. Returns the game to switch to when the fake artist
+    /// or every real artist is gone, or when everyone left has now voted.
+    fn remove_player(&mut self, player: Player, now: u64) -> Option<Game> {
+        self.spectators
+            .retain(|spectator_iter| spectator_iter.id != player.id);
         let index = self
             .players
             .iter()
-            .position(|player_iter| player_iter.id == player.id)
-            .unwrap();
+            .position(|player_iter| player_iter.id == player.id)?;
         self.players.remove(index);
+        self.votes.remove(&player.id);
+        self.reroll_votes.retain(|id| *id != player.id);
+        if player.id == self.fake_artist.id || self.players.len() < 2 {
+            let mut players = self.players.clone();
+            players.append(&mut self.spectators);
+            return Some(Game::Lobby(LobbyState::new(players)));
+        }
+        if index < self.current_player_index {
+            self.current_player_index -= 1;
+        } else if index == self.current_player_index {
+            if let Some(curve) = self.current_curve.take() {
+                self.total_points -= curve.points.len();
+            }
+            self.turn_started = now;
+            if self.current_player_index == self.players.len() {
+                self.current_player_index = 0;
+                self.current_round += 1;
+            }
+        }
+        if self.votes.is_empty() {
+            None
+        } else {
+            self.next()
+        }
     }
     fn update_player(&mut self, player: Player) {
-        let idx = self
+        if let Some(player_iter) = self
             .players
-            .iter()
-            .position(|player_iter| player_iter.id == player.id)
-            .unwrap();
-        self.players[idx] = player
+            .iter_mut()
+            .chain(self.spectators.iter_mut())
+            .find(|player_iter| player_iter.id == player.id)
+        {
+            *player_iter = player
+        }
     }
     pub fn current_player(&self) -> Player {
         self.players[self.current_player_index].clone()
     }
-    pub fn add_chat_msg(&mut self, author: Player, message: &str) {
-        self.chat.push(ChatMessage {
+    /// Stores the message, returning it as it should be shown. From checking
+    /// the word to the vote, the word is masked in the real artists'
+    /// messages so nobody gives it away to the fake artist by accident.
+    pub fn add_chat_msg(&mut self, author: Player, message: &str) -> ChatMessage {
+        let is_real_artist = author.id != self.fake_artist.id
+            && self.players.iter().any(|player| player.id == author.id);
+        let message = if is_real_artist {
+            moderation::mask_words(message, &moderation::word_variants(&self.word.text))
+        } else {
+            message.to_string()
+        };
+        let message = ChatMessage::new(author, &message);
+        self.chat.push(message.clone());
+        message
+    }
+    fn start_curve(&mut self, player: &Player, style: StrokeStyle) -> Result<(), GameError> {
+        let author = self.current_player();
+        if author.id != player.id {
+            return Err(GameError::NotYourTurn);
+        }
+        if !(MIN_STROKE_WIDTH..=MAX_STROKE_WIDTH).contains(&style.width)
+            || style.color != author.color
+        {
+            return Err(GameError::InvalidStroke);
+        }
+        // Points already sent for this turn keep their curve; only a fresh
+        // stroke picks up the new style
+        if self.current_curve.is_none() {
+            self.current_curve = Some(Curve {
+                points: vec![],
+                author,
+                style,
+            });
+        }
+        Ok(())
+    }
+    fn draw(
+        &mut self,
+        player: &Player,
+        mut point: Point,
+        now: u64,
+    ) -> Result<StrokeStyle, GameError> {
+        let author = self.current_player();
+        if author.id != player.id {
+            return Err(GameError::NotYourTurn);
+        }
+        if !point.is_valid() {
+            return Err(GameError::InvalidPoint);
+        }
+        if self.total_points >= MAX_POINTS_PER_GAME {
+            return Err(GameError::DrawingTooLarge);
+        }
+        let curve = self.current_curve.get_or_insert_with(|| Curve {
+            points: vec![],
+            style: StrokeStyle::default_for(&author),
             author,
-            message: message.to_string(),
-        })
-    }
-    fn draw(&mut self, point: Point) {
-        match &mut self.current_curve {
-            Some(curve) => curve.points.push(point),
-            None => {
-                self.current_curve = Some(Curve {
-                    points: vec![point],
-                    author: self.current_player(),
-                })
-            }
+        });
+        if curve.points.len() >= MAX_POINTS_PER_CURVE {
+            return Err(GameError::CurveTooLong);
         }
+        point.t = now.saturating_sub(self.turn_started) as u32;
+        curve.points.push(point);
+        self.total_points += 1;
+        self.phase = Phase::Drawing;
+        Ok(curve.style.clone())
     }
-    fn end_draw(&mut self) {
-        if let Some(curve) = self.current_curve.take() {
+    fn end_draw(&mut self, player: &Player, now: u64) -> Result<(), GameError> {
+        if self.is_voting() {
+            return Err(GameError::DrawingFinished);
+        }
+        if self.current_player().id != player.id {
+            return Err(GameError::NotYourTurn);
+        }
+        if let Some(mut curve) = self.current_curve.take() {
+            curve.points = simplify(&curve.points, config().simplify_tolerance);
             self.curves.push(curve);
         };
         if self.current_player_index < self.players.len() - 1 {
@@ -204,10 +605,59 @@ impl InGameState {
             self.current_player_index = 0;
             self.current_round += 1;
         }
+        self.turn_started = now;
+        Ok(())
     }
-    pub fn is_last_turn(&self) -> bool {
-        self.current_round == self.max_rounds
-            && self.current_player_index == self.players().len() - 1
+    /// Whether every turn has been drawn and it's time to vote.
+    pub fn is_voting(&self) -> bool {
+        self.current_round > self.max_rounds
+    }
+    fn vote_reroll(
+        &mut self,
+        player: Player,
+        words: &mut WordDeck,
+        word_filter: &WordFilter,
+        rng: &mut impl Rng,
+    ) -> Result<(), GameError> {
+        if self.phase != Phase::WordCheck {
+            return Err(GameError::DrawingStarted);
+        }
+        if self.rerolls_left == 0 {
+            return Err(GameError::NoRerollsLeft);
+        }
+        let real_artists = self
+            .players
+            .iter()
+            .filter(|player_iter| player_iter.id != self.fake_artist.id)
+            .collect_vec();
+        if !real_artists
+            .iter()
+            .any(|player_iter| player_iter.id == player.id)
+        {
+            return Err(GameError::CannotVoteReroll);
+        }
+        if !self.reroll_votes.contains(&player.id) {
+            self.reroll_votes.push(player.id);
+        }
+        if self.reroll_votes.len() * 2 > real_artists.len() {
+            // Stay in the same category so the fake artist's hint still holds
+            self.word = if self.word_pool.is_empty() {
+                let same_category = WordFilter {
+                    categories: vec![self.word.category.clone()],
+                    ..word_filter.clone()
+                };
+                words.draw(&same_category, Some(&self.word), rng)
+            } else {
+                self.word_pool
+                    .iter()
+                    .position(|word| word.category == self.word.category)
+                    .map(|index| self.word_pool.remove(index))
+            }
+            .ok_or(GameError::NoWordsAvailable)?;
+            self.reroll_votes.clear();
+            self.rerolls_left -= 1;
+        }
+        Ok(())
     }
     fn vote(&mut self, player: Player, target: Player) -> Option<Game> {
         let player_id = player.id;
@@ -217,24 +667,79 @@ impl InGameState {
     }
 }
 
-#[derive(Debug, Serialize, TS, Clone)]
-enum Winner {
+#[derive(Debug, Deserialize, Serialize, TS, Clone)]
+pub enum Winner {
     FakeArtist,
     RealArtists,
 }
-#[derive(Debug, Serialize, TS, Clone)]
+#[derive(Debug, Deserialize, Serialize, TS, Clone)]
 pub struct GameOverState {
     players: Vec<Player>,
+    spectators: Vec<Player>,
+    curves: Vec<Curve>,
     winner: Winner,
     fake_artist: Player,
+    word: Word,
+    votes: HashMap<Uuid, Uuid>,
+    chat: Vec<ChatMessage>,
 }
 impl GameOverState {
+    /// How the vote went, as announced in chat.
+    pub fn result_message(&self) -> String {
+        match self.winner {
+            Winner::RealArtists => format!(
+                "The artists caught the fake artist, {}! The word was \"{}\".",
+                self.fake_artist.name, self.word.text
+            ),
+            Winner::FakeArtist => format!(
+                "{} was the fake artist and got away with it! The word was \"{}\".",
+                self.fake_artist.name, self.word.text
+            ),
+        }
+    }
+    pub fn record(&self, room_id: Uuid) -> GameRecord {
+        GameRecord {
+            id: Uuid::new_v4(),
+            room_id,
+            finished_at: SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map_or(0, |elapsed| elapsed.as_secs()),
+            players: self.players.clone(),
+            fake_artist: self.fake_artist.clone(),
+            word: self.word.clone(),
+            curves: self.curves.clone(),
+            votes: self.votes.clone(),
+            winner: self.winner.clone(),
+            chat: self.chat.clone(),
+        }
+    }
     fn players(&self) -> Vec<Player> {
         self.players.clone()
     }
+    fn remove_player(&mut self, player: Player) {
+        self.players
+            .retain(|player_iter| player_iter.id != player.id);
+        self.spectators
+            .retain(|spectator_iter| spectator_iter.id != player.id);
+    }
+    pub fn next(&mut self) -> Game {
+        let mut players = self.players.clone();
+        players.append(&mut self.spectators);
+        Game::Lobby(LobbyState::new(players))
+    }
+}
+
+/// The parts of a game never sent to clients, which snapshots carry next to
+/// the serialized `Game`.
+#[derive(Debug, Default, Clone, Deserialize, Serialize)]
+pub struct HiddenState {
+    submitted_words: HashMap<Uuid, Vec<Word>>,
+    word_pool: Vec<Word>,
+    turn_started: u64,
 }
 
-#[derive(Debug, Serialize, TS, Clone)]
+#[allow(clippy::large_enum_variant, clippy::enum_variant_names)]
+#[derive(Debug, Deserialize, Serialize, TS, Clone)]
 #[serde(tag = "state")]
 #[ts(export)]
 pub enum Game {
@@ -245,7 +750,7 @@ pub enum Game {
 
 impl Game {
     pub fn new() -> Self {
-        Game::Lobby(LobbyState { players: vec![] })
+        Game::Lobby(LobbyState::new(vec![]))
     }
     pub fn players(&self) -> Vec<Player> {
         match self {
@@ -257,9 +762,28 @@ impl Game {
     pub fn spectators(&self) -> Vec<Player> {
         match self {
             Game::InGame(in_game) => in_game.spectators(),
+            Game::GameOver(game_over) => game_over.spectators.clone(),
             _ => vec![],
         }
     }
+    /// Every stroke drawn so far, including the one still in progress.
+    pub fn curves(&self) -> Vec<Curve> {
+        match self {
+            Game::InGame(in_game) => in_game
+                .curves
+                .iter()
+                .chain(in_game.current_curve.iter())
+                .cloned()
+                .collect(),
+            Game::GameOver(game_over) => game_over.curves.clone(),
+            Game::Lobby(_) => vec![],
+        }
+    }
+    pub fn everyone(&self) -> Vec<Player> {
+        let mut everyone = self.players();
+        everyone.append(&mut self.spectators());
+        everyone
+    }
     pub fn add_player(&mut self, player: Player) {
         match self {
             Game::Lobby(lobby) => {
@@ -269,14 +793,18 @@ impl Game {
                 // Handle joining mid-game
                 in_game.add_player(player)
             }
-            _ => (),
+            Game::GameOver(game_over) => game_over.spectators.push(player),
         }
     }
-    pub fn remove_player(&mut self, player: Player) {
+    pub fn remove_player(&mut self, player: Player, now: u64) {
         match self {
             Game::Lobby(lobby) => lobby.remove_player(player),
-            Game::InGame(in_game) => in_game.remove_player(player),
-            _ => (),
+            Game::InGame(in_game) => {
+                if let Some(game) = in_game.remove_player(player, now) {
+                    *self = game
+                }
+            }
+            Game::GameOver(game_over) => game_over.remove_player(player),
         }
     }
     pub fn update_player(&mut self, player: Player) {
@@ -286,34 +814,135 @@ impl Game {
             _ => (),
         }
     }
-    pub fn start_game(&mut self) {
-        match self {
-            Game::Lobby(lobby) => {
-                *self = lobby.next();
-            }
-            _ => (),
+    pub fn change_color(&mut self, mut player: Player, color: String) -> Result<Player, GameError> {
+        // A finished game keeps its players as they were
+        if let Game::GameOver(_) = self {
+            return Err(GameError::NotInLobby);
+        }
+        if !lists::colors().contains(&color.as_str()) {
+            return Err(GameError::InvalidColor);
         }
+        if self
+            .everyone()
+            .iter()
+            .any(|other| other.id != player.id && other.color == color)
+        {
+            return Err(GameError::ColorTaken);
+        }
+        player.color = color;
+        self.update_player(player.clone());
+        Ok(player)
+    }
+    pub fn start_game(
+        &mut self,
+        words: &mut WordDeck,
+        settings: &RoomSettings,
+        fake_artists: &mut FakeArtistRotation,
+        rng: &mut impl Rng,
+        now: u64,
+    ) -> Result<(), GameError> {
+        let Game::Lobby(lobby) = self else {
+            return Err(GameError::NotInLobby);
+        };
+        *self = lobby.next(words, settings, fake_artists, rng, now)?;
+        Ok(())
+    }
+    pub fn submit_words(
+        &mut self,
+        player: Player,
+        words: Vec<Word>,
+        words_per_player: u8,
+    ) -> Result<(), GameError> {
+        let Game::Lobby(lobby) = self else {
+            return Err(GameError::NotInLobby);
+        };
+        lobby.submit_words(player, words, words_per_player)
+    }
+    pub fn vote_reroll(
+        &mut self,
+        player: Player,
+        words: &mut WordDeck,
+        word_filter: &WordFilter,
+        rng: &mut impl Rng,
+    ) -> Result<(), GameError> {
+        let Game::InGame(in_game) = self else {
+            return Err(GameError::NotInGame);
+        };
+        in_game.vote_reroll(player, words, word_filter, rng)
     }
-    pub fn draw(&mut self, point: Point) {
+    pub fn is_lobby(&self) -> bool {
+        matches!(self, Game::Lobby(_))
+    }
+    /// The round being drawn and how many there are, while a game is on.
+    pub fn rounds(&self) -> Option<(u8, u8)> {
         match self {
-            Game::InGame(in_game) => in_game.draw(point),
-            _ => (),
+            Game::InGame(in_game) => Some((in_game.current_round, in_game.max_rounds)),
+            _ => None,
         }
     }
-    pub fn end_draw(&mut self) {
+    pub fn hidden_state(&self) -> HiddenState {
         match self {
-            Game::InGame(in_game) => in_game.end_draw(),
-            _ => (),
+            Game::Lobby(lobby) => HiddenState {
+                submitted_words: lobby.word_pool.clone(),
+                ..HiddenState::default()
+            },
+            Game::InGame(in_game) => HiddenState {
+                word_pool: in_game.word_pool.clone(),
+                turn_started: in_game.turn_started,
+                ..HiddenState::default()
+            },
+            Game::GameOver(_) => HiddenState::default(),
         }
     }
-    pub fn vote(&mut self, player: Player, target: Player) {
+    pub fn restore_hidden_state(&mut self, hidden: HiddenState) {
         match self {
+            Game::Lobby(lobby) => lobby.word_pool = hidden.submitted_words,
             Game::InGame(in_game) => {
-                if let Some(Game::GameOver(game_over)) = in_game.vote(player, target) {
-                    *self = Game::GameOver(game_over)
-                }
+                in_game.word_pool = hidden.word_pool;
+                in_game.turn_started = hidden.turn_started;
+                in_game.total_points = in_game
+                    .curves
+                    .iter()
+                    .chain(in_game.current_curve.iter())
+                    .map(|curve| curve.points.len())
+                    .sum();
+            }
+            Game::GameOver(_) => (),
+        }
+    }
+    pub fn rematch(&mut self) {
+        if let Game::GameOver(game_over) = self {
+            *self = game_over.next();
+        }
+    }
+    pub fn start_curve(&mut self, player: &Player, style: StrokeStyle) -> Result<(), GameError> {
+        let Game::InGame(in_game) = self else {
+            return Err(GameError::NotInGame);
+        };
+        in_game.start_curve(player, style)
+    }
+    pub fn draw(
+        &mut self,
+        player: &Player,
+        point: Point,
+        now: u64,
+    ) -> Result<StrokeStyle, GameError> {
+        let Game::InGame(in_game) = self else {
+            return Err(GameError::NotInGame);
+        };
+        in_game.draw(player, point, now)
+    }
+    pub fn end_draw(&mut self, player: &Player, now: u64) -> Result<(), GameError> {
+        let Game::InGame(in_game) = self else {
+            return Err(GameError::NotInGame);
+        };
+        in_game.end_draw(player, now)
+    }
+    pub fn vote(&mut self, player: Player, target: Player) {
+        if let Game::InGame(in_game) = self {
+            if let Some(Game::GameOver(game_over)) = in_game.vote(player, target) {
+                *self = Game::GameOver(game_over)
             }
-            _ => (),
         }
     }
 }
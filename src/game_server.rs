@@ -1,80 +1,1380 @@
+use std::{
+    collections::{HashMap, HashSet},
+    sync::Arc,
+    time::{Duration, Instant},
+};
+
 use axum::async_trait;
+use itertools::Itertools;
 use ractor::{Actor, ActorProcessingErr, ActorRef, RpcReplyPort};
+use rand::{rngs::StdRng, Rng, SeedableRng};
+use serde::{Deserialize, Serialize};
+use socketioxide::{socket::Sid, SocketIo};
+use tracing::error;
+use ts_rs::TS;
+use uuid::Uuid;
+
+use crate::{
+    browser,
+    config::config,
+    encoding::EncodedPoints,
+    game::{
+        ChatMessage, FakeArtistRotation, Game, GameError, HiddenState, Player, Point, StrokeStyle,
+    },
+    history::GameStore,
+    lists::{Word, WordDeck},
+    moderation::{self, MAX_CHAT_LENGTH, MAX_NAME_LENGTH},
+    room_id,
+    room_log::{Command, LogEntry, RoomLog},
+    settings::{RoomSettings, MAX_ROOM_NAME_LENGTH},
+    socket::{DrawEvent, ErrorEvent, NextTurnEvent},
+};
+
+/// How often buffered points are broadcast to the rest of the room.
+const DRAW_TICK: Duration = Duration::from_millis(50);
+/// How long players of a restored room have to reconnect before they're
+/// dropped from it.
+const RESUME_GRACE: Duration = Duration::from_secs(60);
+/// How many chat messages a room keeps for players who join later.
+const CHAT_HISTORY: usize = 100;
+/// How long a room created ahead of time waits for its first player.
+const UNCLAIMED_ROOM_TTL: Duration = Duration::from_secs(24 * 60 * 60);
+
+pub struct GameServer {
+    pub io: SocketIo,
+    /// Where the room's finished games go.
+    pub store: Arc<dyn GameStore>,
+}
+
+/// Points received since the last tick, all from the same stroke.
+struct PendingDraw {
+    sender: Sid,
+    style: StrokeStyle,
+    points: Vec<Point>,
+}
+
+/// Everything a room keeps between games, so rematches don't repeat words
+/// or fake artists until every option has been used.
+pub struct Room {
+    id: Uuid,
+    /// Short code players can type in instead of the link, claimed while
+    /// the room's actor runs.
+    code: String,
+    game: Game,
+    settings: RoomSettings,
+    words: WordDeck,
+    fake_artists: FakeArtistRotation,
+    pending_draw: Option<PendingDraw>,
+    /// Players with a socket attached. Anyone else in the game was restored
+    /// from a snapshot and may still reconnect.
+    connected: HashSet<Uuid>,
+    /// The secret each player got on joining, which takes their seat back
+    /// after a restart. Their id won't do, everyone in the room sees it.
+    resume_tokens: HashMap<Uuid, Uuid>,
+    /// The player who opened the room, or whoever has been in it longest
+    /// once they leave. Only they may change the room's settings.
+    host: Option<Uuid>,
+    rng: StdRng,
+    log: RoomLog,
+    clock: Clock,
+    /// Recent chat across every game played in the room, including the
+    /// server's own announcements.
+    chat: Vec<ChatMessage>,
+    /// Announcements the actor hasn't sent out yet.
+    announcements: Vec<ChatMessage>,
+}
+
+/// Milliseconds since the room was created, carried across restarts. While
+/// replaying a log it's pinned to each entry's timestamp instead.
+enum Clock {
+    Live { started: Instant, offset: u64 },
+    Replay(u64),
+}
+
+impl Clock {
+    fn live(offset: u64) -> Self {
+        Clock::Live {
+            started: Instant::now(),
+            offset,
+        }
+    }
+    fn now(&self) -> u64 {
+        match self {
+            Clock::Live { started, offset } => offset + started.elapsed().as_millis() as u64,
+            Clock::Replay(at) => *at,
+        }
+    }
+}
+
+/// A room as written to disk on shutdown, or as a log found it.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct RoomSnapshot {
+    pub id: Uuid,
+    #[serde(default)]
+    code: String,
+    game: Game,
+    hidden: HiddenState,
+    settings: RoomSettings,
+    words: WordDeck,
+    fake_artists: FakeArtistRotation,
+    log: RoomLog,
+    clock: u64,
+    #[serde(default)]
+    chat: Vec<ChatMessage>,
+    #[serde(default)]
+    connected: HashSet<Uuid>,
+    #[serde(default)]
+    host: Option<Uuid>,
+    #[serde(default)]
+    resume_tokens: HashMap<Uuid, Uuid>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, TS)]
+#[ts(export)]
+pub enum RoomPhase {
+    Lobby,
+    InGame,
+    GameOver,
+}
+
+/// What anyone may see about a room without joining it.
+#[derive(Debug, Serialize, TS)]
+#[ts(export)]
+pub struct RoomStatus {
+    pub id: Uuid,
+    pub code: String,
+    pub phase: RoomPhase,
+    pub players: usize,
+    pub spectators: usize,
+    pub round: Option<u8>,
+    pub max_rounds: Option<u8>,
+    /// Whether joining takes a passcode.
+    pub locked: bool,
+}
+
+/// A public room as shown in the room browser.
+#[derive(Debug, Clone, PartialEq, Serialize, TS)]
+#[ts(export)]
+pub struct RoomListing {
+    pub id: Uuid,
+    pub code: String,
+    pub name: String,
+    pub language: String,
+    pub phase: RoomPhase,
+    pub players: usize,
+    pub locked: bool,
+}
+
+impl Room {
+    pub fn new(id: Uuid) -> Self {
+        Room::seeded(id, new_seed())
+    }
+    /// All of the room's randomness comes from `seed`, so two rooms seeded
+    /// alike and sent the same commands end up in the same state.
+    pub fn seeded(id: Uuid, seed: u64) -> Self {
+        Room {
+            id,
+            code: String::new(),
+            game: Game::new(),
+            settings: RoomSettings::default(),
+            words: WordDeck::default(),
+            fake_artists: FakeArtistRotation::default(),
+            pending_draw: None,
+            connected: HashSet::new(),
+            resume_tokens: HashMap::new(),
+            host: None,
+            rng: StdRng::seed_from_u64(seed),
+            log: RoomLog::new(id, seed),
+            clock: Clock::live(0),
+            chat: vec![],
+            announcements: vec![],
+        }
+    }
+    pub fn game(&self) -> &Game {
+        &self.game
+    }
+    fn phase(&self) -> RoomPhase {
+        match self.game {
+            Game::Lobby(_) => RoomPhase::Lobby,
+            Game::InGame(_) => RoomPhase::InGame,
+            Game::GameOver(_) => RoomPhase::GameOver,
+        }
+    }
+    fn status(&self) -> RoomStatus {
+        let rounds = self.game.rounds();
+        RoomStatus {
+            id: self.id,
+            code: self.code.clone(),
+            phase: self.phase(),
+            players: self.game.players().len(),
+            spectators: self.game.spectators().len(),
+            round: rounds.map(|(round, _)| round),
+            max_rounds: rounds.map(|(_, max_rounds)| max_rounds),
+            locked: self.settings.passcode.is_some(),
+        }
+    }
+    /// How the room shows up in the room browser, if it's public.
+    fn listing(&self) -> Option<RoomListing> {
+        self.settings.public.then(|| RoomListing {
+            id: self.id,
+            code: self.code.clone(),
+            name: self.settings.name.clone(),
+            language: self.settings.language.clone(),
+            phase: self.phase(),
+            players: self.game.players().len(),
+            locked: self.settings.passcode.is_some(),
+        })
+    }
+    fn snapshot(&self) -> RoomSnapshot {
+        RoomSnapshot {
+            id: self.id,
+            code: self.code.clone(),
+            game: self.game.clone(),
+            hidden: self.game.hidden_state(),
+            settings: self.settings.clone(),
+            words: self.words.clone(),
+            fake_artists: self.fake_artists.clone(),
+            log: self.log.clone(),
+            clock: self.clock.now(),
+            chat: self.chat.clone(),
+            connected: self.connected.clone(),
+            host: self.host,
+            resume_tokens: self.resume_tokens.clone(),
+        }
+    }
+    /// Adds the message to the room's chat, dropping the oldest ones past
+    /// `CHAT_HISTORY`.
+    fn post(&mut self, message: ChatMessage) {
+        self.chat.push(message);
+        if self.chat.len() > CHAT_HISTORY {
+            self.chat.drain(..self.chat.len() - CHAT_HISTORY);
+        }
+    }
+    /// Posts a message from the server, to be sent out by the actor.
+    fn announce(&mut self, message: String) {
+        let message = ChatMessage::system(message);
+        self.post(message.clone());
+        self.announcements.push(message);
+    }
+    /// Appends a command the room has just applied to the log. Anything it
+    /// turned away stays out, replaying it would change nothing.
+    fn record(&mut self, at: u64, command: Command) {
+        self.log.append(at, command);
+    }
+    /// Runs a command that draws on the room's randomness, putting the game,
+    /// word deck and RNG back as they were if it fails so the log, which
+    /// won't have it, still replays to the same state.
+    fn rolling_back<T>(
+        &mut self,
+        command: impl FnOnce(&mut Self) -> Result<T, GameError>,
+    ) -> Result<T, GameError> {
+        let saved = (
+            self.game.clone(),
+            self.words.clone(),
+            self.fake_artists.clone(),
+            self.rng.clone(),
+        );
+        let result = command(self);
+        if result.is_err() {
+            (self.game, self.words, self.fake_artists, self.rng) = saved;
+        }
+        result
+    }
+    /// Applies a logged command the same way the actor applied it live.
+    pub fn replay(&mut self, entry: &LogEntry) {
+        self.clock = Clock::Replay(entry.at);
+        match entry.command.clone() {
+            Command::Join { resume } => {
+                self.join(resume);
+            }
+            Command::UpdatePlayer { player } => {
+                self.update_player(player);
+            }
+            Command::ChangeColor { player, color } => {
+                let _ = self.change_color(player, color);
+            }
+            Command::Leave { player } => {
+                self.leave(player);
+            }
+            Command::UpdateSettings { player, settings } => {
+                let _ = self.update_settings(player, settings);
+            }
+            Command::SubmitWords { player, words } => {
+                let _ = self.submit_words(player, words);
+            }
+            Command::StartGame => {
+                let _ = self.start_game();
+            }
+            Command::VoteReroll { player } => {
+                let _ = self.vote_reroll(player);
+            }
+            Command::Rematch => {
+                self.rematch();
+            }
+            Command::DrawStart { player, style } => {
+                let _ = self.draw_start(player, style);
+            }
+            Command::Draw { player, points } => {
+                self.draw(player, points);
+            }
+            Command::DrawEnd { player } => {
+                let _ = self.draw_end(player);
+            }
+            Command::VoteFake { player, target } => {
+                self.vote_fake(player, target);
+            }
+            Command::Chat { player, message } => {
+                self.chat(player, message);
+            }
+            Command::DropAbsent => {
+                self.drop_absent();
+            }
+            Command::Restored { seed } => self.restored(seed),
+        }
+    }
+    /// The restored player the resume token `resume` belongs to, if they
+    /// haven't reconnected.
+    fn restorable(&self, resume: Option<Uuid>) -> Option<Player> {
+        let resume = resume?;
+        let (&id, _) = self
+            .resume_tokens
+            .iter()
+            .find(|(_, token)| **token == resume)?;
+        if self.connected.contains(&id) {
+            return None;
+        }
+        self.game
+            .everyone()
+            .into_iter()
+            .find(|player| player.id == id)
+    }
+    /// Checks the room's passcode, if it has one. Players coming back after
+    /// a restart were let in before and don't need it again.
+    fn admit(&self, resume: Option<Uuid>, passcode: Option<&str>) -> Result<(), GameError> {
+        match &self.settings.passcode {
+            Some(expected)
+                if passcode != Some(expected.as_str()) && self.restorable(resume).is_none() =>
+            {
+                Err(GameError::WrongPasscode)
+            }
+            _ => Ok(()),
+        }
+    }
+    fn join(&mut self, resume: Option<Uuid>) -> Player {
+        self.record(self.clock.now(), Command::Join { resume });
+        let player = match self.restorable(resume) {
+            Some(player) => {
+                self.announce(format!("{} is back", player.name));
+                player
+            }
+            None => {
+                let player = Player::random(&self.game.everyone(), &mut self.rng);
+                self.game.add_player(player.clone());
+                self.host.get_or_insert(player.id);
+                let token = uuid::Builder::from_random_bytes(self.rng.gen()).into_uuid();
+                self.resume_tokens.insert(player.id, token);
+                self.announce(format!("{} joined", player.name));
+                player
+            }
+        };
+        self.connected.insert(player.id);
+        player
+    }
+    /// Returns the player as stored. A name with nothing left once cleaned
+    /// up leaves the old one in place.
+    fn update_player(&mut self, mut player: Player) -> Player {
+        player.name = moderation::clean(&player.name, MAX_NAME_LENGTH);
+        if player.name.is_empty() {
+            if let Some(current) = self.game.everyone().into_iter().find(|p| p.id == player.id) {
+                return current;
+            }
+        }
+        self.record(
+            self.clock.now(),
+            Command::UpdatePlayer {
+                player: player.clone(),
+            },
+        );
+        self.game.update_player(player.clone());
+        player
+    }
+    fn change_color(&mut self, player: Player, color: String) -> Result<Player, GameError> {
+        let command = Command::ChangeColor {
+            player: player.clone(),
+            color: color.clone(),
+        };
+        let player = self.game.change_color(player, color)?;
+        self.record(self.clock.now(), command);
+        Ok(player)
+    }
+    /// Returns the next turn if the player left while it was theirs.
+    fn leave(&mut self, player: Player) -> Option<(usize, bool)> {
+        let now = self.clock.now();
+        self.record(
+            now,
+            Command::Leave {
+                player: player.clone(),
+            },
+        );
+        self.connected.remove(&player.id);
+        self.remove_player(player, now)
+    }
+    /// Takes the player out of the game, calling it off if there's no one
+    /// left to play with. Returns the next turn if it was theirs.
+    fn remove_player(&mut self, player: Player, now: u64) -> Option<(usize, bool)> {
+        let (was_in_game, was_their_turn) = match &self.game {
+            Game::InGame(game) => (
+                true,
+                !game.is_voting() && game.current_player().id == player.id,
+            ),
+            _ => (false, false),
+        };
+        self.announce(format!("{} left", player.name));
+        let was_host = self.host == Some(player.id);
+        self.resume_tokens.remove(&player.id);
+        self.game.remove_player(player, now);
+        if was_host {
+            self.host = self.game.everyone().first().map(|player| player.id);
+        }
+        match &self.game {
+            Game::Lobby(_) if was_in_game => {
+                self.announce("Not enough players left, back to the lobby".to_string());
+                None
+            }
+            Game::GameOver(game_over) if was_in_game => {
+                self.announce(game_over.result_message());
+                None
+            }
+            Game::InGame(_) if was_their_turn => self.announce_turn(),
+            _ => None,
+        }
+    }
+    /// `player` is who asked for the change, `None` when it came in over
+    /// the API, which checks its own token.
+    fn update_settings(
+        &mut self,
+        player: Option<Player>,
+        mut settings: RoomSettings,
+    ) -> Result<(), GameError> {
+        if player
+            .as_ref()
+            .is_some_and(|player| self.host != Some(player.id))
+        {
+            return Err(GameError::NotHost);
+        }
+        if !self.game.is_lobby() {
+            return Err(GameError::NotInLobby);
+        }
+        settings.validate()?;
+        // The name shows up in the room browser for everyone to read
+        settings.name = moderation::clean(&settings.name, MAX_ROOM_NAME_LENGTH);
+        self.record(
+            self.clock.now(),
+            Command::UpdateSettings {
+                player,
+                settings: settings.clone(),
+            },
+        );
+        self.settings = settings;
+        Ok(())
+    }
+    fn submit_words(&mut self, player: Player, words: Vec<Word>) -> Result<(), GameError> {
+        let command = Command::SubmitWords {
+            player: player.clone(),
+            words: words.clone(),
+        };
+        self.game
+            .submit_words(player, words, self.settings.words_per_player)?;
+        self.record(self.clock.now(), command);
+        Ok(())
+    }
+    fn start_game(&mut self) -> Result<(), GameError> {
+        let now = self.clock.now();
+        self.rolling_back(|room| {
+            room.game.start_game(
+                &mut room.words,
+                &room.settings,
+                &mut room.fake_artists,
+                &mut room.rng,
+                now,
+            )
+        })?;
+        self.record(now, Command::StartGame);
+        if let Game::InGame(game) = &self.game {
+            let first = game.current_player();
+            self.announce(format!("The game is on! {} draws first", first.name));
+        }
+        Ok(())
+    }
+    fn vote_reroll(&mut self, player: Player) -> Result<(), GameError> {
+        let command = Command::VoteReroll {
+            player: player.clone(),
+        };
+        self.rolling_back(|room| {
+            room.game.vote_reroll(
+                player,
+                &mut room.words,
+                &room.settings.word_filter,
+                &mut room.rng,
+            )
+        })?;
+        self.record(self.clock.now(), command);
+        Ok(())
+    }
+    /// Returns the finished game's log, the next game gets a fresh one.
+    fn rematch(&mut self) -> Option<RoomLog> {
+        if !matches!(self.game, Game::GameOver(_)) {
+            return None;
+        }
+        self.record(self.clock.now(), Command::Rematch);
+        self.game.rematch();
+        // Reseeding keeps the new log replayable from its starting snapshot
+        let seed = self.rng.gen();
+        self.rng = StdRng::seed_from_u64(seed);
+        let finished = std::mem::replace(&mut self.log, RoomLog::new(self.id, seed));
+        self.log = finished.next(seed, self.snapshot());
+        Some(finished)
+    }
+    fn draw_start(&mut self, player: Player, style: StrokeStyle) -> Result<(), GameError> {
+        let command = Command::DrawStart {
+            player: player.clone(),
+            style: style.clone(),
+        };
+        self.game.start_curve(&player, style)?;
+        self.record(self.clock.now(), command);
+        Ok(())
+    }
+    /// Returns the points that made it into the drawing along with their
+    /// stroke's style, and the error that cut the batch short, if any.
+    fn draw(
+        &mut self,
+        player: Player,
+        points: Vec<Point>,
+    ) -> (Vec<(Point, StrokeStyle)>, Option<GameError>) {
+        let now = self.clock.now();
+        let mut drawn = vec![];
+        let mut error = None;
+        for point in points {
+            match self.game.draw(&player, point.clone(), now) {
+                Ok(style) => drawn.push((point, style)),
+                Err(err) => {
+                    error = Some(err);
+                    break;
+                }
+            }
+        }
+        if !drawn.is_empty() {
+            let points = drawn.iter().map(|(point, _)| point.clone()).collect();
+            self.record(now, Command::Draw { player, points });
+        }
+        (drawn, error)
+    }
+    /// Returns the next player's index and whether the turn that just ended
+    /// was the last one.
+    fn draw_end(&mut self, player: Player) -> Result<(usize, bool), GameError> {
+        let now = self.clock.now();
+        self.game.end_draw(&player, now)?;
+        self.record(now, Command::DrawEnd { player });
+        self.announce_turn().ok_or(GameError::NotInGame)
+    }
+    /// Announces who draws next, or that it's time to vote. Returns the
+    /// drawing player's index and whether the drawing is finished.
+    fn announce_turn(&mut self) -> Option<(usize, bool)> {
+        let Game::InGame(game) = &self.game else {
+            return None;
+        };
+        let message = if game.is_voting() {
+            "Time to vote: who's the fake artist?".to_string()
+        } else {
+            format!("It's {}'s turn", game.current_player().name)
+        };
+        self.announce(message);
+        self.turn()
+    }
+    /// The drawing player's index and whether it's time to vote instead.
+    fn turn(&self) -> Option<(usize, bool)> {
+        let Game::InGame(game) = &self.game else {
+            return None;
+        };
+        Some((game.current_player_index, game.is_voting()))
+    }
+    /// Returns whether this vote ended the game.
+    fn vote_fake(&mut self, player: Player, target: Player) -> bool {
+        if !matches!(self.game, Game::InGame(_)) {
+            return false;
+        }
+        self.record(
+            self.clock.now(),
+            Command::VoteFake {
+                player: player.clone(),
+                target: target.clone(),
+            },
+        );
+        self.game.vote(player, target);
+        match &self.game {
+            Game::GameOver(game_over) => {
+                self.announce(game_over.result_message());
+                true
+            }
+            _ => false,
+        }
+    }
+    /// Returns the message as others should see it, or nothing if it was
+    /// empty once cleaned up.
+    fn chat(&mut self, player: Player, message: String) -> Option<ChatMessage> {
+        let message = moderation::clean(&message, MAX_CHAT_LENGTH);
+        if message.is_empty() {
+            return None;
+        }
+        self.record(
+            self.clock.now(),
+            Command::Chat {
+                player: player.clone(),
+                message: message.clone(),
+            },
+        );
+        let message = match &mut self.game {
+            Game::InGame(game) => game.add_chat_msg(player, &message),
+            _ => ChatMessage::new(player, &message),
+        };
+        self.post(message.clone());
+        Some(message)
+    }
+    /// Removes restored players that never reconnected, returning them and
+    /// the next turn if it was one of theirs.
+    fn drop_absent(&mut self) -> (Vec<Player>, Option<(usize, bool)>) {
+        let absent = self
+            .game
+            .everyone()
+            .into_iter()
+            .filter(|player| !self.connected.contains(&player.id))
+            .collect_vec();
+        if absent.is_empty() {
+            return (absent, None);
+        }
+        let now = self.clock.now();
+        self.record(now, Command::DropAbsent);
+        let mut turn_moved = false;
+        for player in &absent {
+            turn_moved |= self.remove_player(player.clone(), now).is_some();
+        }
+        // Later removals may have shifted the turn again
+        (absent, turn_moved.then(|| self.turn()).flatten())
+    }
+    fn restored(&mut self, seed: u64) {
+        self.record(self.clock.now(), Command::Restored { seed });
+        self.connected.clear();
+        self.rng = StdRng::seed_from_u64(seed);
+    }
+    fn is_empty(&self) -> bool {
+        self.game.players().is_empty() && self.game.spectators().is_empty()
+    }
+}
+
+/// The configured seed, or a random one when none is set.
+fn new_seed() -> u64 {
+    config().seed.unwrap_or_else(rand::random)
+}
+
+impl Room {
+    /// Picks a room back up where the snapshot left it, with its RNG seeded
+    /// from `seed`.
+    pub fn resumed(snapshot: RoomSnapshot, seed: u64) -> Self {
+        let mut game = snapshot.game;
+        game.restore_hidden_state(snapshot.hidden);
+        Room {
+            id: snapshot.id,
+            code: snapshot.code,
+            game,
+            settings: snapshot.settings,
+            words: snapshot.words,
+            fake_artists: snapshot.fake_artists,
+            pending_draw: None,
+            connected: snapshot.connected,
+            resume_tokens: snapshot.resume_tokens,
+            host: snapshot.host,
+            rng: StdRng::seed_from_u64(seed),
+            log: snapshot.log,
+            clock: Clock::live(snapshot.clock),
+            chat: snapshot.chat,
+            announcements: vec![],
+        }
+    }
+}
 
-use crate::game::{Game, Player, Point};
+impl From<RoomSnapshot> for Room {
+    fn from(snapshot: RoomSnapshot) -> Self {
+        let seed = new_seed();
+        let mut room = Room::resumed(snapshot, seed);
+        room.restored(seed);
+        room
+    }
+}
+
+impl GameServer {
+    fn flush_draw(&self, myself: &ActorRef<Message>, pending_draw: &mut Option<PendingDraw>) {
+        let Some(pending) = pending_draw.take() else {
+            return;
+        };
+        self.io
+            .to(myself.get_name().unwrap())
+            .except(pending.sender)
+            .emit(
+                "draw",
+                DrawEvent {
+                    points: EncodedPoints::encode(&pending.points),
+                    style: pending.style,
+                },
+            )
+            .ok();
+    }
+    /// Tells the room who left and, if a game was on, what became of it.
+    fn broadcast_leave(
+        &self,
+        myself: &ActorRef<Message>,
+        room: &Room,
+        was_in_game: bool,
+        left: Vec<Player>,
+        next_turn: Option<(usize, bool)>,
+    ) {
+        let name = myself.get_name().unwrap();
+        for player in left {
+            self.io.to(name.clone()).emit("leave", player).ok();
+        }
+        match &room.game {
+            Game::Lobby(_) if was_in_game => {
+                self.io.to(name).emit("lobby", room.game.clone()).ok();
+            }
+            Game::GameOver(_) if was_in_game => {
+                save_game(&self.store, room);
+                self.io.to(name).emit("game_over", room.game.clone()).ok();
+            }
+            _ => {
+                if let Some((current_player_index, is_last_turn)) = next_turn {
+                    self.io
+                        .to(name)
+                        .emit(
+                            "next_turn",
+                            NextTurnEvent {
+                                current_player_index,
+                                is_last_turn,
+                            },
+                        )
+                        .ok();
+                }
+            }
+        }
+    }
+}
+
+/// Writes the log to the configured directory, if there is one.
+async fn write_log(log: &RoomLog) {
+    let Some(log_dir) = &config().log_dir else {
+        return;
+    };
+    let path = format!("{}/room-{}-{}.json", log_dir, log.room_id, log.part);
+    let json = match serde_json::to_vec(log) {
+        Ok(json) => json,
+        Err(err) => return error!("Failed to serialize {}: {}", path, err),
+    };
+    if let Err(err) = tokio::fs::write(&path, json).await {
+        error!("Failed to write {}: {}", path, err);
+    }
+}
+
+/// Adds the room's finished game to the history, if the room keeps one.
+fn save_game(store: &Arc<dyn GameStore>, room: &Room) {
+    let Game::GameOver(game_over) = &room.game else {
+        return;
+    };
+    if !room.settings.keep_history {
+        return;
+    }
+    let record = game_over.record(room.id);
+    let store = store.clone();
+    tokio::task::spawn_blocking(move || {
+        if let Err(err) = store.save(&record) {
+            error!("Failed to save game {}: {}", record.id, err);
+        }
+    });
+}
+
+/// Looks up the actor running a room, if anyone is playing in it.
+pub fn find_room(id: Uuid) -> Option<ActorRef<Message>> {
+    ractor::registry::where_is(format!("room:{}", id)).map(ActorRef::from)
+}
+
+/// Everything a player needs to know about the room they just joined.
+pub struct Joined {
+    pub player: Player,
+    /// Takes the player's seat back if the server restarts.
+    pub resume_token: Uuid,
+    pub game: Game,
+    pub settings: RoomSettings,
+    pub code: String,
+    pub chat: Vec<ChatMessage>,
+}
 
-pub struct GameServer;
 pub enum Message {
-    Join(Player, RpcReplyPort<Game>),
-    UpdatePlayer(Player, RpcReplyPort<Game>),
+    GetGame(RpcReplyPort<Game>),
+    GetStatus(RpcReplyPort<RoomStatus>),
+    /// Joins as a new player, or takes back the restored player the resume
+    /// token belongs to if they haven't reconnected yet.
+    Join(
+        Option<Uuid>,
+        Option<String>,
+        RpcReplyPort<Result<Joined, GameError>>,
+    ),
+    UpdatePlayer(Player, RpcReplyPort<(Player, Game)>),
+    ChangeColor(
+        Player,
+        String,
+        RpcReplyPort<Result<(Player, Game), GameError>>,
+    ),
     Leave(Player, RpcReplyPort<Game>),
-    StartGame(RpcReplyPort<Game>),
-    Draw(Point),
-    DrawEnd(RpcReplyPort<(usize, bool)>),
+    UpdateSettings(
+        Option<Player>,
+        RoomSettings,
+        RpcReplyPort<Result<RoomSettings, GameError>>,
+    ),
+    SubmitWords(Player, Vec<Word>, RpcReplyPort<Result<Game, GameError>>),
+    StartGame(RpcReplyPort<Result<Game, GameError>>),
+    Rematch(RpcReplyPort<Game>),
+    DrawStart(Player, StrokeStyle, RpcReplyPort<Result<(), GameError>>),
+    Draw(Sid, Player, Vec<Point>),
+    FlushDraw,
+    DrawEnd(Player, RpcReplyPort<Result<(usize, bool), GameError>>),
+    VoteReroll(Player, RpcReplyPort<Result<Game, GameError>>),
     VoteFake(Player, Player, RpcReplyPort<Game>),
-    Chat(Player, String),
+    Chat(Player, String, RpcReplyPort<Option<ChatMessage>>),
+    Snapshot(RpcReplyPort<RoomSnapshot>),
+    DropAbsent,
+    /// Disconnects everyone in the room and shuts it down.
+    Close(RpcReplyPort<()>),
 }
 
 #[async_trait]
 impl Actor for GameServer {
     type Msg = Message;
-    type State = Game;
-    type Arguments = ();
+    type State = Room;
+    type Arguments = Room;
     async fn pre_start(
         &self,
-        _myself: ActorRef<Self::Msg>,
-        _args: Self::Arguments,
+        myself: ActorRef<Self::Msg>,
+        mut room: Self::Arguments,
     ) -> Result<Self::State, ActorProcessingErr> {
-        Ok(Game::new())
+        room.code = room_id::claim_code(room.id, &room.code);
+        myself.send_interval(DRAW_TICK, || Message::FlushDraw);
+        if room.is_empty() {
+            // Rooms are usually joined right away, but ones created over the
+            // API may sit empty until their game is due
+            myself.send_after(UNCLAIMED_ROOM_TTL, || Message::DropAbsent);
+        } else {
+            myself.send_after(RESUME_GRACE, || Message::DropAbsent);
+        }
+        browser::update(&self.io, room.id, room.listing());
+        Ok(room)
+    }
+    async fn post_stop(
+        &self,
+        _myself: ActorRef<Self::Msg>,
+        room: &mut Self::State,
+    ) -> Result<(), ActorProcessingErr> {
+        browser::update(&self.io, room.id, None);
+        room_id::release_code(room.id, &room.code);
+        write_log(&room.log).await;
+        Ok(())
     }
     async fn handle(
         &self,
         myself: ActorRef<Self::Msg>,
         message: Self::Msg,
-        game: &mut Self::State,
+        room: &mut Self::State,
     ) -> Result<(), ActorProcessingErr> {
         match message {
-            Message::Join(player, reply) => {
-                game.add_player(player);
-                let _ = reply.send(game.clone());
+            Message::GetGame(reply) => {
+                let _ = reply.send(room.game.clone());
+            }
+            Message::GetStatus(reply) => {
+                let _ = reply.send(room.status());
+            }
+            Message::Join(resume, passcode, reply) => {
+                let result = room.admit(resume, passcode.as_deref()).map(|_| {
+                    let player = room.join(resume);
+                    Joined {
+                        resume_token: room.resume_tokens[&player.id],
+                        player,
+                        game: room.game.clone(),
+                        settings: room.settings.clone(),
+                        code: room.code.clone(),
+                        chat: room.chat.clone(),
+                    }
+                });
+                let _ = reply.send(result);
             }
             Message::UpdatePlayer(player, reply) => {
-                game.update_player(player);
-                let _ = reply.send(game.clone());
+                let player = room.update_player(player);
+                let _ = reply.send((player, room.game.clone()));
+            }
+            Message::ChangeColor(player, color, reply) => {
+                let result = room.change_color(player, color);
+                let _ = reply.send(result.map(|player| (player, room.game.clone())));
+            }
+            Message::UpdateSettings(player, settings, reply) => {
+                let result = room.update_settings(player, settings);
+                let _ = reply.send(result.map(|_| room.settings.clone()));
+            }
+            Message::SubmitWords(player, words, reply) => {
+                let result = room.submit_words(player, words);
+                let _ = reply.send(result.map(|_| room.game.clone()));
             }
             Message::StartGame(reply) => {
-                game.start_game();
-                let _ = reply.send(game.clone());
-            }
-            Message::Draw(point) => game.draw(point),
-            Message::DrawEnd(reply) => {
-                let is_last_turn = if let Game::InGame(game) = game {
-                    game.is_last_turn()
-                } else {
-                    false
-                };
-                game.end_draw();
-                if let Game::InGame(game) = game {
-                    let _ = reply.send((game.current_player_index, is_last_turn));
+                let result = room.start_game();
+                let _ = reply.send(result.map(|_| room.game.clone()));
+            }
+            Message::VoteReroll(player, reply) => {
+                let result = room.vote_reroll(player);
+                let _ = reply.send(result.map(|_| room.game.clone()));
+            }
+            Message::Rematch(reply) => {
+                if let Some(finished) = room.rematch() {
+                    write_log(&finished).await;
                 }
+                let _ = reply.send(room.game.clone());
+            }
+            Message::DrawStart(player, style, reply) => {
+                let _ = reply.send(room.draw_start(player, style));
+            }
+            Message::Draw(sender, player, points) => {
+                let (drawn, error) = room.draw(player, points);
+                for (point, style) in drawn {
+                    let same_stroke = room
+                        .pending_draw
+                        .as_ref()
+                        .is_some_and(|pending| pending.sender == sender && pending.style == style);
+                    if !same_stroke {
+                        self.flush_draw(&myself, &mut room.pending_draw);
+                        room.pending_draw = Some(PendingDraw {
+                            sender,
+                            style,
+                            points: vec![],
+                        });
+                    }
+                    room.pending_draw.as_mut().unwrap().points.push(point);
+                }
+                if let Some(error) = error {
+                    self.io
+                        .to(sender)
+                        .emit("game_error", ErrorEvent::from(error))
+                        .ok();
+                }
+            }
+            Message::FlushDraw => self.flush_draw(&myself, &mut room.pending_draw),
+            Message::DrawEnd(player, reply) => {
+                self.flush_draw(&myself, &mut room.pending_draw);
+                let _ = reply.send(room.draw_end(player));
             }
             Message::VoteFake(player, target, reply) => {
-                game.vote(player, target);
-                let _ = reply.send(game.clone());
+                if room.vote_fake(player, target) {
+                    save_game(&self.store, room);
+                }
+                let _ = reply.send(room.game.clone());
             }
-            Message::Chat(author, message) => {
-                if let Game::InGame(game) = game {
-                    game.add_chat_msg(author, &message);
-                };
+            Message::Chat(author, message, reply) => {
+                let _ = reply.send(room.chat(author, message));
             }
             Message::Leave(player, reply) => {
-                game.remove_player(player);
-                if game.players().len() == 0 {
+                let was_in_game = room.phase() == RoomPhase::InGame;
+                let next_turn = room.leave(player.clone());
+                self.broadcast_leave(&myself, room, was_in_game, vec![player], next_turn);
+                if room.is_empty() {
+                    myself.stop(None)
+                };
+                let _ = reply.send(room.game.clone());
+            }
+            Message::Snapshot(reply) => {
+                self.flush_draw(&myself, &mut room.pending_draw);
+                let _ = reply.send(room.snapshot());
+            }
+            Message::DropAbsent => {
+                let was_in_game = room.phase() == RoomPhase::InGame;
+                let (absent, next_turn) = room.drop_absent();
+                self.broadcast_leave(&myself, room, was_in_game, absent, next_turn);
+                if room.is_empty() {
                     myself.stop(None)
                 };
-                let _ = reply.send(game.clone());
             }
+            Message::Close(reply) => {
+                let name = myself.get_name().unwrap();
+                self.io.to(name.clone()).emit("room_closed", ()).ok();
+                self.io.to(name).disconnect().ok();
+                myself.stop(Some("closed".to_string()));
+                let _ = reply.send(());
+            }
+        }
+        for announcement in room.announcements.drain(..) {
+            self.io
+                .to(myself.get_name().unwrap())
+                .emit("chat_msg", announcement)
+                .ok();
         }
+        browser::update(&self.io, room.id, room.listing());
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn in_game(room: &Room) -> &crate::game::InGameState {
+        match room.game() {
+            Game::InGame(game) => game,
+            game => panic!("expected a game in progress, got {:?}", game),
+        }
+    }
+
+    fn started_room(players: usize) -> Room {
+        seeded_room(players, 7)
+    }
+
+    fn seeded_room(players: usize, seed: u64) -> Room {
+        let mut room = Room::seeded(Uuid::nil(), seed);
+        for _ in 0..players {
+            room.join(None);
+        }
+        room.start_game().unwrap();
+        room
+    }
+
+    fn end_turn(room: &mut Room) -> Result<(usize, bool), GameError> {
+        let player = in_game(room).current_player();
+        room.draw_end(player)
+    }
+
+    fn fake_artist(room: &Room) -> Uuid {
+        let game = serde_json::to_value(room.game()).unwrap();
+        serde_json::from_value(game["fake_artist"]["id"].clone()).unwrap()
+    }
+
+    #[test]
+    fn last_player_leaving_on_their_turn_passes_it_on() {
+        // The fake artist leaving would call the game off instead
+        let mut room = (0..)
+            .map(|seed| seeded_room(4, seed))
+            .find(|room| room.game().players()[3].id != fake_artist(room))
+            .unwrap();
+        let players = room.game().players();
+        let last = players.len() - 1;
+        for _ in 0..last {
+            end_turn(&mut room).unwrap();
+        }
+        let point = Point {
+            x: 0.5,
+            y: 0.25,
+            t: 0,
+        };
+        room.draw(players[last].clone(), vec![point.clone()]);
+
+        assert_eq!(room.leave(players[last].clone()), Some((0, false)));
+        let game = in_game(&room);
+        assert_eq!(game.current_player().id, players[0].id);
+        assert!(room.game().curves().is_empty());
+        let (drawn, error) = room.draw(players[0].clone(), vec![point]);
+        assert_eq!(drawn.len(), 1);
+        assert!(error.is_none());
+    }
+
+    #[test]
+    fn game_is_called_off_when_only_spectators_remain() {
+        let mut room = started_room(3);
+        let spectator = room.join(None);
+        for player in room.game().players() {
+            room.leave(player);
+        }
+        assert!(room.game().is_lobby());
+        assert_eq!(room.game().players().len(), 1);
+        assert_eq!(room.game().players()[0].id, spectator.id);
+        assert!(matches!(
+            room.draw_end(spectator),
+            Err(GameError::NotInGame)
+        ));
+    }
+
+    #[test]
+    fn only_the_drawing_player_ends_their_turn() {
+        let mut room = started_room(3);
+        let spectator = room.join(None);
+        let players = room.game().players();
+        for player in [&players[1], &spectator] {
+            assert!(matches!(
+                room.draw_end(player.clone()),
+                Err(GameError::NotYourTurn)
+            ));
+        }
+        for _ in 0..players.len() * 2 {
+            end_turn(&mut room).unwrap();
+        }
+        assert!(in_game(&room).is_voting());
+        assert!(matches!(
+            end_turn(&mut room),
+            Err(GameError::DrawingFinished)
+        ));
+        assert!(in_game(&room).is_voting());
+        rebuilt_matches(&room);
+    }
+
+    #[test]
+    fn game_is_called_off_when_the_fake_artist_leaves() {
+        let mut room = started_room(4);
+        let fake_artist = fake_artist(&room);
+        let player = room
+            .game()
+            .players()
+            .into_iter()
+            .find(|player| player.id == fake_artist)
+            .unwrap();
+        room.leave(player);
+        assert!(room.game().is_lobby());
+        assert_eq!(room.game().players().len(), 3);
+    }
+
+    #[test]
+    fn only_the_resume_token_takes_a_seat_back() {
+        let mut room = started_room(3);
+        room.settings.passcode = Some("secret".to_string());
+        let players = room.game().players();
+        let tokens = players
+            .iter()
+            .map(|player| room.resume_tokens[&player.id])
+            .collect_vec();
+        let mut room = Room::resumed(room.snapshot(), 7);
+        room.restored(7);
+
+        let taken = players[0].id;
+        assert!(matches!(
+            room.admit(Some(taken), None),
+            Err(GameError::WrongPasscode)
+        ));
+        assert_ne!(room.join(Some(taken)).id, taken);
+        room.admit(Some(tokens[0]), None).unwrap();
+        assert_eq!(room.join(Some(tokens[0])).id, taken);
+        assert_ne!(room.join(Some(tokens[0])).id, taken);
+        assert_eq!(room.join(Some(tokens[1])).id, players[1].id);
+    }
+
+    #[test]
+    fn fake_artist_never_gets_their_own_word() {
+        for seed in 0..20 {
+            let mut room = Room::seeded(Uuid::nil(), seed);
+            let players = (0..4).map(|_| room.join(None)).collect_vec();
+            let settings = RoomSettings {
+                words_per_player: 2,
+                max_rerolls: 1,
+                ..RoomSettings::default()
+            };
+            room.update_settings(None, settings).unwrap();
+            for player in &players {
+                let words = (0..2)
+                    .map(|i| Word {
+                        category: "things".to_string(),
+                        text: format!("{} {i}", player.id),
+                        difficulty: Default::default(),
+                    })
+                    .collect();
+                room.submit_words(player.clone(), words).unwrap();
+            }
+            room.start_game().unwrap();
+            let fake_artist = fake_artist(&room);
+            let word = |room: &Room| {
+                let game = serde_json::to_value(room.game()).unwrap();
+                game["word"]["text"].as_str().unwrap().to_string()
+            };
+            let first = word(&room);
+            assert!(!first.starts_with(&fake_artist.to_string()));
+            for player in players.iter().filter(|player| player.id != fake_artist) {
+                let _ = room.vote_reroll(player.clone());
+            }
+            let rerolled = word(&room);
+            assert_ne!(rerolled, first);
+            assert!(!rerolled.starts_with(&fake_artist.to_string()));
+        }
+    }
+
+    #[test]
+    fn submitted_words_are_cleaned_up() {
+        let mut room = Room::seeded(Uuid::nil(), 7);
+        let players = (0..3).map(|_| room.join(None)).collect_vec();
+        let settings = RoomSettings {
+            words_per_player: 1,
+            ..RoomSettings::default()
+        };
+        room.update_settings(None, settings).unwrap();
+        let word = Word {
+            category: " \u{202E}animals ".to_string(),
+            text: "c\u{200B}at\u{0007}".to_string(),
+            difficulty: Default::default(),
+        };
+        for player in players {
+            room.submit_words(player, vec![word.clone()]).unwrap();
+        }
+        room.start_game().unwrap();
+        let game = serde_json::to_value(room.game()).unwrap();
+        assert_eq!(game["word"]["category"], "animals");
+        assert_eq!(game["word"]["text"], "cat");
+    }
+
+    #[test]
+    fn colors_stay_put_once_the_game_is_over() {
+        let mut room = started_room(3);
+        let players = room.game().players();
+        for voter in &players {
+            room.vote_fake(voter.clone(), players[0].clone());
+        }
+        assert!(matches!(room.game(), Game::GameOver(_)));
+        let color = crate::lists::colors()
+            .into_iter()
+            .find(|color| players.iter().all(|player| player.color != *color))
+            .unwrap();
+        assert!(matches!(
+            room.change_color(players[0].clone(), color.to_string()),
+            Err(GameError::NotInLobby)
+        ));
+    }
+
+    #[test]
+    fn only_the_host_changes_settings() {
+        let mut room = Room::seeded(Uuid::nil(), 7);
+        let host = room.join(None);
+        let guest = room.join(None);
+        let settings = RoomSettings {
+            name: "  Friday\u{202E}   night  ".to_string(),
+            ..RoomSettings::default()
+        };
+        assert!(matches!(
+            room.update_settings(Some(guest.clone()), settings.clone()),
+            Err(GameError::NotHost)
+        ));
+        room.update_settings(Some(host.clone()), settings.clone())
+            .unwrap();
+        assert_eq!(room.settings.name, "Friday night");
+
+        room.leave(host.clone());
+        assert!(matches!(
+            room.update_settings(Some(host), settings.clone()),
+            Err(GameError::NotHost)
+        ));
+        room.update_settings(Some(guest), settings).unwrap();
+        rebuilt_matches(&room);
+    }
+
+    fn rebuilt_matches(room: &Room) {
+        let rebuilt = crate::room_log::rebuild(&room.log);
+        assert_eq!(
+            serde_json::to_value(&rebuilt).unwrap(),
+            serde_json::to_value(room.game()).unwrap()
+        );
+    }
+
+    #[test]
+    fn rejected_commands_stay_out_of_the_log() {
+        let mut room = started_room(3);
+        let logged = room.log.entries.len();
+        let waiting = room.game().players()[1].clone();
+        let point = Point {
+            x: 0.5,
+            y: 0.25,
+            t: 0,
+        };
+        let (drawn, error) = room.draw(waiting.clone(), vec![point.clone(); 10]);
+        assert!(drawn.is_empty());
+        assert!(matches!(error, Some(GameError::NotYourTurn)));
+        assert!(room.chat(waiting, " \u{200B} ".to_string()).is_none());
+        assert!(room.update_settings(None, RoomSettings::default()).is_err());
+        room.rematch();
+        assert_eq!(room.log.entries.len(), logged);
+    }
+
+    #[test]
+    fn tied_vote_rebuilds_the_same_game() {
+        let mut room = started_room(4);
+        let players = room.game().players();
+        for _ in 0..players.len() * 2 {
+            end_turn(&mut room).unwrap();
+        }
+        for (voter, target) in players.iter().zip([0, 0, 1, 1]) {
+            room.vote_fake(voter.clone(), players[target].clone());
+        }
+        assert!(matches!(room.game(), Game::GameOver(_)));
+        rebuilt_matches(&room);
+    }
+
+    #[test]
+    fn each_game_gets_its_own_log() {
+        let mut room = started_room(3);
+        let players = room.game().players();
+        for voter in &players {
+            room.vote_fake(voter.clone(), players[0].clone());
+        }
+        let finished = room.rematch().unwrap();
+        assert!(finished.entries.len() > players.len());
+        assert_eq!(room.log.part, 1);
+        assert!(room.log.entries.is_empty());
+        room.start_game().unwrap();
+        end_turn(&mut room).unwrap();
+        rebuilt_matches(&room);
+    }
+
+    /// Plays a few games in a room seeded with `seed`, returning how each
+    /// one started.
+    fn seeded_games(seed: u64) -> Vec<serde_json::Value> {
+        let mut room = Room::seeded(Uuid::nil(), seed);
+        for _ in 0..4 {
+            room.join(None);
+        }
+        let mut games = vec![];
+        for _ in 0..3 {
+            room.start_game().unwrap();
+            games.push(serde_json::to_value(room.game()).unwrap());
+            let players = room.game().players();
+            for voter in &players {
+                room.vote_fake(voter.clone(), players[0].clone());
+            }
+            room.rematch();
+        }
+        games
+    }
+
+    #[test]
+    fn rooms_seeded_alike_play_out_alike() {
+        assert_eq!(seeded_games(42), seeded_games(42));
+        assert_ne!(seeded_games(42), seeded_games(43));
+    }
+
+    #[test]
+    fn points_below_the_canvas_are_rejected() {
+        let mut room = started_room(3);
+        let drawing = room.game().players()[0].clone();
+        let point = |y| Point { x: 0.5, y, t: 0 };
+        let (drawn, error) = room.draw(drawing, vec![point(0.5625), point(0.6)]);
+        assert_eq!(drawn.len(), 1);
+        assert!(matches!(error, Some(GameError::InvalidPoint)));
+    }
+
+    #[test]
+    fn word_is_masked_while_checking_it() {
+        let mut room = started_room(3);
+        let game = serde_json::to_value(room.game()).unwrap();
+        assert_eq!(game["phase"], "WordCheck");
+        let word = game["word"]["text"].as_str().unwrap().to_string();
+        let real_artist = room
+            .game()
+            .players()
+            .into_iter()
+            .find(|player| player.id != fake_artist(&room))
+            .unwrap();
+        let message = room.chat(real_artist, format!("is it {word}?")).unwrap();
+        let message = serde_json::to_value(message).unwrap();
+        assert!(!message["message"].as_str().unwrap().contains(&word));
+    }
+}
@@ -0,0 +1,461 @@
+use std::{collections::HashMap, error::Error, fmt, sync::Arc, sync::Mutex};
+
+use serde::{Deserialize, Serialize};
+use ts_rs::TS;
+use uuid::Uuid;
+
+use crate::{
+    game::{ChatMessage, Curve, Player, Winner},
+    lists::Word,
+};
+
+/// Everything worth keeping about a finished game once its room is gone.
+#[derive(Debug, Clone, Deserialize, Serialize, TS)]
+#[ts(export)]
+pub struct GameRecord {
+    pub id: Uuid,
+    pub room_id: Uuid,
+    /// Seconds since the Unix epoch.
+    #[ts(type = "number")]
+    pub finished_at: u64,
+    pub players: Vec<Player>,
+    pub fake_artist: Player,
+    pub word: Word,
+    pub curves: Vec<Curve>,
+    pub votes: HashMap<Uuid, Uuid>,
+    pub winner: Winner,
+    pub chat: Vec<ChatMessage>,
+}
+
+impl GameRecord {
+    /// Swaps every player id for the order the player was first seen in.
+    /// Real ids take players back into a restored room, and shouldn't be
+    /// handed to strangers browsing the gallery.
+    fn anonymized(mut self) -> Self {
+        let mut ids = HashMap::new();
+        let mut anonymize = |id: &mut Uuid| {
+            let next = Uuid::from_u128(ids.len() as u128 + 1);
+            *id = *ids.entry(*id).or_insert(next);
+        };
+        for player in self
+            .players
+            .iter_mut()
+            .chain([&mut self.fake_artist])
+            .chain(self.curves.iter_mut().map(|curve| &mut curve.author))
+            .chain(self.chat.iter_mut().filter_map(ChatMessage::author_mut))
+        {
+            anonymize(&mut player.id);
+        }
+        self.votes = self
+            .votes
+            .into_iter()
+            .map(|(mut voter, mut target)| {
+                anonymize(&mut voter);
+                anonymize(&mut target);
+                (voter, target)
+            })
+            .collect();
+        self
+    }
+}
+
+/// A finished game as the gallery shows it, without the room it was played
+/// in and with anonymized player ids.
+#[derive(Debug, Serialize, TS)]
+#[ts(export)]
+pub struct PublicGame {
+    pub id: Uuid,
+    #[ts(type = "number")]
+    pub finished_at: u64,
+    pub players: Vec<Player>,
+    pub fake_artist: Player,
+    pub word: Word,
+    pub curves: Vec<Curve>,
+    pub votes: HashMap<Uuid, Uuid>,
+    pub winner: Winner,
+    pub chat: Vec<ChatMessage>,
+}
+
+impl From<GameRecord> for PublicGame {
+    fn from(record: GameRecord) -> Self {
+        let record = record.anonymized();
+        PublicGame {
+            id: record.id,
+            finished_at: record.finished_at,
+            players: record.players,
+            fake_artist: record.fake_artist,
+            word: record.word,
+            curves: record.curves,
+            votes: record.votes,
+            winner: record.winner,
+            chat: record.chat,
+        }
+    }
+}
+
+/// What the gallery lists for each game, leaving out the drawing and chat.
+#[derive(Debug, Serialize, TS)]
+#[ts(export)]
+pub struct GameSummary {
+    pub id: Uuid,
+    #[ts(type = "number")]
+    pub finished_at: u64,
+    pub players: Vec<Player>,
+    pub fake_artist: Player,
+    pub word: Word,
+    pub winner: Winner,
+}
+
+impl From<GameRecord> for GameSummary {
+    fn from(record: GameRecord) -> Self {
+        let record = record.anonymized();
+        GameSummary {
+            id: record.id,
+            finished_at: record.finished_at,
+            players: record.players,
+            fake_artist: record.fake_artist,
+            word: record.word,
+            winner: record.winner,
+        }
+    }
+}
+
+pub const DEFAULT_PAGE_SIZE: u32 = 20;
+pub const MAX_PAGE_SIZE: u32 = 100;
+
+/// Filters for listing games, newest first. `since` and `until` are seconds
+/// since the Unix epoch, `page` counts from zero.
+#[derive(Debug, Deserialize, TS)]
+#[serde(default)]
+#[ts(export)]
+pub struct GameQuery {
+    pub category: Option<String>,
+    #[ts(type = "number | null")]
+    pub since: Option<u64>,
+    #[ts(type = "number | null")]
+    pub until: Option<u64>,
+    pub page: u32,
+    pub page_size: u32,
+}
+
+impl Default for GameQuery {
+    fn default() -> Self {
+        GameQuery {
+            category: None,
+            since: None,
+            until: None,
+            page: 0,
+            page_size: DEFAULT_PAGE_SIZE,
+        }
+    }
+}
+
+impl GameQuery {
+    fn matches(&self, record: &GameRecord) -> bool {
+        !matches!(&self.category, Some(category) if record.word.category != *category)
+            && !matches!(self.since, Some(since) if record.finished_at < since)
+            && !matches!(self.until, Some(until) if record.finished_at >= until)
+    }
+    fn limit(&self) -> u32 {
+        self.page_size.clamp(1, MAX_PAGE_SIZE)
+    }
+    fn offset(&self) -> u64 {
+        self.page as u64 * self.limit() as u64
+    }
+}
+
+#[derive(Debug, Serialize, TS)]
+#[ts(export)]
+pub struct GamePage {
+    pub games: Vec<GameSummary>,
+    pub page: u32,
+    pub page_size: u32,
+    pub total: u32,
+}
+
+#[derive(Debug)]
+pub struct StoreError(String);
+
+impl fmt::Display for StoreError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "Game history unavailable: {}", self.0)
+    }
+}
+
+impl Error for StoreError {}
+
+impl From<serde_json::Error> for StoreError {
+    fn from(error: serde_json::Error) -> Self {
+        StoreError(error.to_string())
+    }
+}
+
+pub trait GameStore: Send + Sync {
+    fn save(&self, record: &GameRecord) -> Result<(), StoreError>;
+    fn get(&self, id: Uuid) -> Result<Option<GameRecord>, StoreError>;
+    fn list(&self, query: &GameQuery) -> Result<GamePage, StoreError>;
+}
+
+/// Keeps games for as long as the process lives. Used when the server is
+/// built without SQLite, and handy for tests.
+#[cfg_attr(feature = "sqlite", allow(dead_code))]
+#[derive(Default)]
+pub struct MemoryStore {
+    games: Mutex<Vec<GameRecord>>,
+}
+
+impl GameStore for MemoryStore {
+    fn save(&self, record: &GameRecord) -> Result<(), StoreError> {
+        self.games.lock().unwrap().push(record.clone());
+        Ok(())
+    }
+    fn get(&self, id: Uuid) -> Result<Option<GameRecord>, StoreError> {
+        let games = self.games.lock().unwrap();
+        Ok(games.iter().find(|record| record.id == id).cloned())
+    }
+    fn list(&self, query: &GameQuery) -> Result<GamePage, StoreError> {
+        let games = self.games.lock().unwrap();
+        let matching = games
+            .iter()
+            .rev()
+            .filter(|record| query.matches(record))
+            .collect::<Vec<_>>();
+        Ok(GamePage {
+            total: matching.len() as u32,
+            games: matching
+                .into_iter()
+                .skip(query.offset() as usize)
+                .take(query.limit() as usize)
+                .cloned()
+                .map(GameSummary::from)
+                .collect(),
+            page: query.page,
+            page_size: query.limit(),
+        })
+    }
+}
+
+#[cfg(feature = "sqlite")]
+mod sqlite {
+    use std::sync::Mutex;
+
+    use rusqlite::{params, Connection, OptionalExtension};
+    use uuid::Uuid;
+
+    use super::{GamePage, GameQuery, GameRecord, GameStore, GameSummary, StoreError};
+
+    impl From<rusqlite::Error> for StoreError {
+        fn from(error: rusqlite::Error) -> Self {
+            StoreError(error.to_string())
+        }
+    }
+
+    /// The whole record is stored as JSON, with the columns worth searching
+    /// by copied out next to it.
+    pub struct SqliteStore {
+        connection: Mutex<Connection>,
+    }
+
+    impl SqliteStore {
+        pub fn open(path: &str) -> Result<Self, StoreError> {
+            let connection = Connection::open(path)?;
+            connection.execute_batch(
+                "CREATE TABLE IF NOT EXISTS games (
+                    id TEXT PRIMARY KEY,
+                    room_id TEXT NOT NULL,
+                    finished_at INTEGER NOT NULL,
+                    category TEXT NOT NULL,
+                    word TEXT NOT NULL,
+                    record TEXT NOT NULL
+                );
+                CREATE INDEX IF NOT EXISTS games_finished_at ON games (finished_at);
+                CREATE INDEX IF NOT EXISTS games_category ON games (category);",
+            )?;
+            Ok(SqliteStore {
+                connection: Mutex::new(connection),
+            })
+        }
+    }
+
+    impl GameStore for SqliteStore {
+        fn save(&self, record: &GameRecord) -> Result<(), StoreError> {
+            self.connection.lock().unwrap().execute(
+                "INSERT INTO games (id, room_id, finished_at, category, word, record)
+                VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
+                params![
+                    record.id.to_string(),
+                    record.room_id.to_string(),
+                    record.finished_at as i64,
+                    record.word.category,
+                    record.word.text,
+                    serde_json::to_string(record)?,
+                ],
+            )?;
+            Ok(())
+        }
+        fn get(&self, id: Uuid) -> Result<Option<GameRecord>, StoreError> {
+            let record: Option<String> = self
+                .connection
+                .lock()
+                .unwrap()
+                .query_row(
+                    "SELECT record FROM games WHERE id = ?1",
+                    params![id.to_string()],
+                    |row| row.get(0),
+                )
+                .optional()?;
+            Ok(record
+                .map(|record| serde_json::from_str(&record))
+                .transpose()?)
+        }
+        fn list(&self, query: &GameQuery) -> Result<GamePage, StoreError> {
+            const FILTER: &str = "(?1 IS NULL OR category = ?1)
+                AND (?2 IS NULL OR finished_at >= ?2)
+                AND (?3 IS NULL OR finished_at < ?3)";
+            let filter = params![
+                query.category,
+                query.since.map(|since| since as i64),
+                query.until.map(|until| until as i64),
+            ];
+            let connection = self.connection.lock().unwrap();
+            let total: u32 = connection.query_row(
+                &format!("SELECT COUNT(*) FROM games WHERE {FILTER}"),
+                filter,
+                |row| row.get(0),
+            )?;
+            let mut statement = connection.prepare(&format!(
+                "SELECT record FROM games WHERE {FILTER}
+                ORDER BY finished_at DESC LIMIT {} OFFSET {}",
+                query.limit(),
+                query.offset()
+            ))?;
+            let games = statement
+                .query_map(filter, |row| row.get::<_, String>(0))?
+                .map(|record| {
+                    let record: GameRecord = serde_json::from_str(&record?)?;
+                    Ok(GameSummary::from(record))
+                })
+                .collect::<Result<_, StoreError>>()?;
+            Ok(GamePage {
+                games,
+                page: query.page,
+                page_size: query.limit(),
+                total,
+            })
+        }
+    }
+}
+
+/// Opens the store finished games are written to, as configured.
+pub fn open_store() -> Result<Arc<dyn GameStore>, StoreError> {
+    #[cfg(feature = "sqlite")]
+    {
+        let path = &crate::config::config().database_path;
+        Ok(Arc::new(sqlite::SqliteStore::open(path)?))
+    }
+    #[cfg(not(feature = "sqlite"))]
+    {
+        Ok(Arc::new(MemoryStore::default()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::lists::Difficulty;
+
+    fn record(finished_at: u64, category: &str) -> GameRecord {
+        let players = (0..3)
+            .map(|i| Player {
+                id: Uuid::new_v4(),
+                name: format!("Player {i}"),
+                color: "#000000".to_string(),
+            })
+            .collect::<Vec<_>>();
+        GameRecord {
+            id: Uuid::new_v4(),
+            room_id: Uuid::new_v4(),
+            finished_at,
+            fake_artist: players[0].clone(),
+            players,
+            word: Word {
+                category: category.to_string(),
+                text: "Cat".to_string(),
+                difficulty: Difficulty::default(),
+            },
+            curves: vec![],
+            votes: HashMap::new(),
+            winner: Winner::RealArtists,
+            chat: vec![],
+        }
+    }
+
+    /// Saves a few games to `store` and checks that it lists and fetches
+    /// them the way `GameQuery` describes.
+    fn lists_and_fetches_saved_games(store: &dyn GameStore) {
+        let records = [
+            record(10, "Animals"),
+            record(20, "Food"),
+            record(30, "Animals"),
+            record(40, "Animals"),
+        ];
+        for record in &records {
+            store.save(record).unwrap();
+        }
+        let ids = |page: &GamePage| page.games.iter().map(|game| game.id).collect::<Vec<_>>();
+
+        let page = store.list(&GameQuery::default()).unwrap();
+        assert_eq!(page.total, 4);
+        let newest_first = records
+            .iter()
+            .rev()
+            .map(|record| record.id)
+            .collect::<Vec<_>>();
+        assert_eq!(ids(&page), newest_first);
+
+        let query = GameQuery {
+            category: Some("Animals".to_string()),
+            since: Some(15),
+            until: Some(40),
+            ..GameQuery::default()
+        };
+        let page = store.list(&query).unwrap();
+        assert_eq!(page.total, 1);
+        assert_eq!(ids(&page), [records[2].id]);
+
+        let query = GameQuery {
+            category: Some("Animals".to_string()),
+            page: 1,
+            page_size: 2,
+            ..GameQuery::default()
+        };
+        let page = store.list(&query).unwrap();
+        assert_eq!((page.total, page.page, page.page_size), (3, 1, 2));
+        assert_eq!(ids(&page), [records[0].id]);
+
+        let query = GameQuery {
+            page: 2,
+            page_size: 2,
+            ..GameQuery::default()
+        };
+        let page = store.list(&query).unwrap();
+        assert_eq!(page.total, 4);
+        assert!(page.games.is_empty());
+
+        let fetched = store.get(records[1].id).unwrap().unwrap();
+        assert_eq!(fetched.room_id, records[1].room_id);
+        assert_eq!(fetched.word.category, "Food");
+        assert!(store.get(Uuid::new_v4()).unwrap().is_none());
+    }
+
+    #[test]
+    fn memory_store_lists_and_fetches_saved_games() {
+        lists_and_fetches_saved_games(&MemoryStore::default());
+    }
+
+    #[cfg(feature = "sqlite")]
+    #[test]
+    fn sqlite_store_lists_and_fetches_saved_games() {
+        lists_and_fetches_saved_games(&sqlite::SqliteStore::open(":memory:").unwrap());
+    }
+}
@@ -0,0 +1,320 @@
+use std::sync::Arc;
+
+use axum::{
+    body::Bytes,
+    extract::{FromRef, Path, Query, State},
+    http::{
+        header::{AUTHORIZATION, CONTENT_TYPE, HOST},
+        HeaderMap, StatusCode,
+    },
+    response::{Html, IntoResponse},
+    routing::get,
+    Json, Router,
+};
+use ractor::{call, Actor, ActorRef};
+use serde::{Deserialize, Serialize};
+use socketioxide::SocketIo;
+use tokio::sync::Semaphore;
+use tracing::{error, info};
+use ts_rs::TS;
+use uuid::Uuid;
+
+use crate::{
+    browser,
+    config::config,
+    game::{Curve, Game},
+    game_server::{self, GameServer, Message, Room, RoomListing, RoomStatus},
+    history::{GamePage, GameQuery, GameRecord, GameStore, PublicGame, StoreError},
+    render, room_id,
+    settings::RoomSettings,
+};
+
+const DEFAULT_PNG_WIDTH: u32 = 1200;
+const MAX_PNG_WIDTH: u32 = 2400;
+/// How many drawings may be rasterized at once, shared by every PNG route.
+/// Link previews fetch these unprompted, so the rest wait their turn.
+const MAX_CONCURRENT_RENDERS: usize = 2;
+static RENDERS: Semaphore = Semaphore::const_new(MAX_CONCURRENT_RENDERS);
+
+/// What the handlers share: the socket server rooms broadcast on and the
+/// store finished games are kept in.
+#[derive(Clone)]
+pub struct AppState {
+    pub io: SocketIo,
+    pub store: Arc<dyn GameStore>,
+}
+
+impl FromRef<AppState> for SocketIo {
+    fn from_ref(state: &AppState) -> Self {
+        state.io.clone()
+    }
+}
+
+impl FromRef<AppState> for Arc<dyn GameStore> {
+    fn from_ref(state: &AppState) -> Self {
+        state.store.clone()
+    }
+}
+
+pub fn router(state: AppState) -> Router {
+    let mut rooms = get(list_rooms);
+    let mut room = get(room_status);
+    // Without a token anyone could open rooms or close other people's games
+    if config().api_token.is_some() {
+        rooms = rooms.post(create_room);
+        room = room.delete(close_room);
+    }
+    Router::new()
+        .route("/rooms", rooms)
+        .route("/rooms/:room_id", room)
+        .route("/room/:room_id", get(room_page))
+        .route("/room/:room_id/drawing.svg", get(drawing_svg))
+        .route("/room/:room_id/drawing.png", get(drawing_png))
+        .route("/games", get(list_games))
+        .route("/games/:game_id", get(get_game))
+        .route("/games/:game_id/drawing.svg", get(game_drawing_svg))
+        .route("/games/:game_id/drawing.png", get(game_drawing_png))
+        .with_state(state)
+}
+
+/// Creating and closing rooms takes the configured API token.
+fn authorize(headers: &HeaderMap) -> Result<(), StatusCode> {
+    let Some(token) = &config().api_token else {
+        return Err(StatusCode::UNAUTHORIZED);
+    };
+    let bearer = headers
+        .get(AUTHORIZATION)
+        .and_then(|value| value.to_str().ok())
+        .and_then(|value| value.strip_prefix("Bearer "));
+    if bearer == Some(token.as_str()) {
+        Ok(())
+    } else {
+        Err(StatusCode::UNAUTHORIZED)
+    }
+}
+
+#[derive(Serialize, TS)]
+#[ts(export)]
+struct CreatedRoom {
+    id: Uuid,
+    code: String,
+    /// Link players open to join, relative to the server.
+    path: String,
+}
+
+/// Opens a room nobody has joined yet. The body may hold the room's
+/// settings; an empty body keeps the defaults.
+async fn create_room(
+    State(AppState { io, store }): State<AppState>,
+    headers: HeaderMap,
+    body: Bytes,
+) -> Result<(StatusCode, Json<CreatedRoom>), (StatusCode, String)> {
+    authorize(&headers).map_err(|status| (status, String::new()))?;
+    let settings = if body.is_empty() {
+        None
+    } else {
+        let settings: RoomSettings = serde_json::from_slice(&body)
+            .map_err(|err| (StatusCode::UNPROCESSABLE_ENTITY, err.to_string()))?;
+        // Check before spawning, a rejected room would otherwise linger
+        settings
+            .validate()
+            .map_err(|err| (StatusCode::UNPROCESSABLE_ENTITY, err.to_string()))?;
+        Some(settings)
+    };
+    let id = Uuid::new_v4();
+    let (room, _) = Actor::spawn(
+        Some(format!("room:{}", id)),
+        GameServer { io, store },
+        Room::new(id),
+    )
+    .await
+    .map_err(|_| (StatusCode::INTERNAL_SERVER_ERROR, String::new()))?;
+    if let Some(settings) = settings {
+        let result = call!(room, Message::UpdateSettings, None, settings)
+            .map_err(|_| (StatusCode::INTERNAL_SERVER_ERROR, String::new()))?;
+        if let Err(err) = result {
+            room.stop(None);
+            return Err((StatusCode::UNPROCESSABLE_ENTITY, err.to_string()));
+        }
+    }
+    let status = call!(room, Message::GetStatus)
+        .map_err(|_| (StatusCode::INTERNAL_SERVER_ERROR, String::new()))?;
+    info!("Room {} created over the API as {}", id, status.code);
+    Ok((
+        StatusCode::CREATED,
+        Json(CreatedRoom {
+            id,
+            path: format!("/room/{}", status.code),
+            code: status.code,
+        }),
+    ))
+}
+
+async fn list_rooms() -> Json<Vec<RoomListing>> {
+    Json(browser::listings())
+}
+
+async fn room_status(Path(room_id): Path<String>) -> Result<Json<RoomStatus>, StatusCode> {
+    let room = find_room(&room_id)?;
+    call!(room, Message::GetStatus)
+        .map(Json)
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)
+}
+
+/// Disconnects everyone in the room and shuts it down.
+async fn close_room(
+    Path(room_id): Path<String>,
+    headers: HeaderMap,
+) -> Result<StatusCode, StatusCode> {
+    authorize(&headers)?;
+    let room = find_room(&room_id)?;
+    call!(room, Message::Close).map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+    info!("Room {} closed over the API", room_id);
+    Ok(StatusCode::NO_CONTENT)
+}
+
+fn find_room(room_id: &str) -> Result<ActorRef<Message>, StatusCode> {
+    room_id::parse(room_id)
+        .and_then(game_server::find_room)
+        .ok_or(StatusCode::NOT_FOUND)
+}
+
+async fn find_game(room_id: &str) -> Result<Game, StatusCode> {
+    let room = find_room(room_id)?;
+    call!(room, Message::GetGame).map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)
+}
+
+async fn drawing_svg(Path(room_id): Path<String>) -> Result<impl IntoResponse, StatusCode> {
+    let game = find_game(&room_id).await?;
+    Ok(svg_response(&game.curves()))
+}
+
+fn svg_response(curves: &[Curve]) -> impl IntoResponse {
+    ([(CONTENT_TYPE, "image/svg+xml")], render::svg(curves))
+}
+
+#[derive(Deserialize)]
+struct PngParams {
+    width: Option<u32>,
+}
+
+async fn drawing_png(
+    Path(room_id): Path<String>,
+    Query(params): Query<PngParams>,
+) -> Result<impl IntoResponse, StatusCode> {
+    let game = find_game(&room_id).await?;
+    png_response(game.curves(), params).await
+}
+
+async fn png_response(
+    curves: Vec<Curve>,
+    params: PngParams,
+) -> Result<impl IntoResponse, StatusCode> {
+    let width = params
+        .width
+        .unwrap_or(DEFAULT_PNG_WIDTH)
+        .clamp(16, MAX_PNG_WIDTH);
+    let permit = RENDERS
+        .acquire()
+        .await
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+    // Rasterizing is CPU bound, keep it off the async workers
+    let png = tokio::task::spawn_blocking(move || {
+        let _permit = permit;
+        render::png(&curves, width)
+    })
+    .await
+    .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?;
+    Ok(([(CONTENT_TYPE, "image/png")], png))
+}
+
+/// Runs a query against the game history off the async workers.
+async fn query_store<T, F>(store: Arc<dyn GameStore>, query: F) -> Result<T, StatusCode>
+where
+    T: Send + 'static,
+    F: FnOnce(&dyn GameStore) -> Result<T, StoreError> + Send + 'static,
+{
+    tokio::task::spawn_blocking(move || query(store.as_ref()))
+        .await
+        .map_err(|_| StatusCode::INTERNAL_SERVER_ERROR)?
+        .map_err(|err| {
+            error!("{}", err);
+            StatusCode::INTERNAL_SERVER_ERROR
+        })
+}
+
+async fn find_record(store: Arc<dyn GameStore>, game_id: Uuid) -> Result<GameRecord, StatusCode> {
+    query_store(store, move |store| store.get(game_id))
+        .await?
+        .ok_or(StatusCode::NOT_FOUND)
+}
+
+async fn list_games(
+    State(store): State<Arc<dyn GameStore>>,
+    Query(query): Query<GameQuery>,
+) -> Result<Json<GamePage>, StatusCode> {
+    query_store(store, move |store| store.list(&query))
+        .await
+        .map(Json)
+}
+
+async fn get_game(
+    State(store): State<Arc<dyn GameStore>>,
+    Path(game_id): Path<Uuid>,
+) -> Result<Json<PublicGame>, StatusCode> {
+    find_record(store, game_id)
+        .await
+        .map(|record| Json(record.into()))
+}
+
+async fn game_drawing_svg(
+    State(store): State<Arc<dyn GameStore>>,
+    Path(game_id): Path<Uuid>,
+) -> Result<impl IntoResponse, StatusCode> {
+    let record = find_record(store, game_id).await?;
+    Ok(svg_response(&record.curves))
+}
+
+async fn game_drawing_png(
+    State(store): State<Arc<dyn GameStore>>,
+    Path(game_id): Path<Uuid>,
+    Query(params): Query<PngParams>,
+) -> Result<impl IntoResponse, StatusCode> {
+    let record = find_record(store, game_id).await?;
+    png_response(record.curves, params).await
+}
+
+/// Serves the app with Open Graph tags so shared room links preview the
+/// current drawing.
+async fn room_page(
+    Path(room_id): Path<String>,
+    headers: HeaderMap,
+) -> Result<Html<String>, StatusCode> {
+    let index = tokio::fs::read_to_string("frontend/dist/index.html")
+        .await
+        .map_err(|_| StatusCode::NOT_FOUND)?;
+    let (Some(host), Some(room_id)) = (
+        headers.get(HOST).and_then(|host| host.to_str().ok()),
+        room_id::parse(&room_id),
+    ) else {
+        return Ok(Html(index));
+    };
+    // Both headers are client controlled, so only take a known scheme and
+    // escape the host before putting them into the page
+    let scheme = match headers
+        .get("x-forwarded-proto")
+        .and_then(|proto| proto.to_str().ok())
+    {
+        Some("https") => "https",
+        _ => "http",
+    };
+    let host = render::escape(host);
+    let meta = format!(
+        r#"<meta property="og:image" content="{scheme}://{host}/room/{room_id}/drawing.png" /><meta property="og:image:type" content="image/png" /><meta name="twitter:card" content="summary_large_image" />"#
+    );
+    Ok(Html(index.replacen(
+        "</head>",
+        &format!("{meta}</head>"),
+        1,
+    )))
+}
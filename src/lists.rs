@@ -1,21 +1,45 @@
-use rand::seq::IteratorRandom;
-use serde::Serialize;
+use rand::{seq::IteratorRandom, Rng};
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
 use ts_rs::TS;
 
-pub fn random_artist() -> Artist<'static> {
-    artists().into_iter().choose(&mut rand::thread_rng()).unwrap()
+/// Picks an artist name nobody in `taken` is using, numbering repeats once
+/// every artist has been handed out.
+pub fn random_artist(taken: &[&str], rng: &mut impl Rng) -> String {
+    let artists = artists();
+    if let Some(artist) = artists
+        .iter()
+        .filter(|artist| !taken.contains(&artist.name))
+        .choose(rng)
+    {
+        return artist.name.to_string();
+    }
+    let artist = artists.iter().choose(rng).unwrap();
+    (2..)
+        .map(|n| format!("{} {}", artist.name, n))
+        .find(|name| !taken.contains(&name.as_str()))
+        .unwrap()
 }
 
-pub fn random_word() -> Word<'static> {
-    words().into_iter().choose(&mut rand::thread_rng()).unwrap()
+/// Picks a color nobody in `taken` is using, falling back to any color once
+/// the palette runs out.
+pub fn random_color(taken: &[&str], rng: &mut impl Rng) -> &'static str {
+    let colors = colors();
+    colors
+        .iter()
+        .filter(|color| !taken.contains(color))
+        .choose(rng)
+        .or_else(|| colors.iter().choose(rng))
+        .unwrap()
 }
 
-pub fn random_color() -> &'static str {
-    colors().into_iter().choose(&mut rand::thread_rng()).unwrap()
-}
-
-fn colors() -> Vec<&'static str> {
-    vec!["#F52E2E", "#5463FF", "#FFC717", "#1F9E40", "#FF6619"]
+pub fn colors() -> Vec<&'static str> {
+    vec![
+      "#F52E2E", "#5463FF", "#FFC717", "#1F9E40", "#FF6619",
+      "#8E44AD", "#00A6A6", "#E83E8C", "#6D4C41", "#212121",
+      "#7CB342", "#0D47A1", "#00BCD4", "#9E9D24", "#AD1457",
+      "#FF8A80", "#B388FF", "#607D8B", "#D500F9", "#80D8FF",
+    ]
 }
 
 #[derive(Debug)]
@@ -50,955 +74,1099 @@ fn artists() -> Vec<Artist<'static>> {
     ]
 }
 
-#[derive(Debug, Serialize, TS, Clone)]
+#[derive(
+    Debug, Deserialize, Serialize, TS, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash,
+)]
+#[ts(export)]
+pub enum Difficulty {
+    Easy,
+    #[default]
+    Medium,
+    Hard,
+}
+
+#[derive(Debug, Deserialize, Serialize, TS, Clone, PartialEq, Eq, Hash)]
+#[ts(export)]
+pub struct Word {
+    pub category: String,
+    pub text: String,
+    #[serde(default)]
+    pub difficulty: Difficulty,
+}
+
+/// Which words a room is willing to play with. An empty `categories` list
+/// allows every category not listed in `excluded_categories`.
+#[derive(Debug, Deserialize, Serialize, TS, Clone, Default)]
+#[serde(default)]
 #[ts(export)]
-pub struct Word<'a>{
-    pub category: &'a str,
-    pub text: &'a str,
+pub struct WordFilter {
+    pub categories: Vec<String>,
+    pub excluded_categories: Vec<String>,
+    pub max_difficulty: Option<Difficulty>,
+}
+
+impl WordFilter {
+    pub fn allows(&self, word: &Word) -> bool {
+        (self.categories.is_empty() || self.categories.contains(&word.category))
+            && !self.excluded_categories.contains(&word.category)
+            && !matches!(self.max_difficulty, Some(max) if word.difficulty > max)
+    }
 }
 
-fn words () -> Vec<Word<'static>>{
+/// The words a room has played, so none comes up twice until every word
+/// allowed by the filter has.
+#[derive(Debug, Default, Clone, Deserialize, Serialize)]
+pub struct WordDeck {
+    #[serde(default)]
+    used: HashSet<Word>,
+}
+
+impl WordDeck {
+    /// Picks a word the filter allows that hasn't come up yet, other than
+    /// `current`. Once they all have, only those words go back in the deck.
+    /// Returns `None` when the filter doesn't allow any other word at all.
+    pub fn draw(
+        &mut self,
+        filter: &WordFilter,
+        current: Option<&Word>,
+        rng: &mut impl Rng,
+    ) -> Option<Word> {
+        let allowed = words()
+            .into_iter()
+            .filter(|word| filter.allows(word) && Some(word) != current)
+            .collect::<Vec<_>>();
+        if allowed.iter().all(|word| self.used.contains(word)) {
+            for word in &allowed {
+                self.used.remove(word);
+            }
+        }
+        let word = allowed
+            .into_iter()
+            .filter(|word| !self.used.contains(word))
+            .choose(rng)?;
+        self.used.insert(word.clone());
+        Some(word)
+    }
+}
+
+fn word(category: &str, text: &str, difficulty: Difficulty) -> Word {
+    Word {
+        category: category.to_string(),
+        text: text.to_string(),
+        difficulty,
+    }
+}
+
+fn words () -> Vec<Word>{
+    use Difficulty::*;
     vec![
-      Word{ category: "accessory", text: "bandana" },
-      Word{ category: "accessory", text: "earring" },
-      Word{ category: "accessory", text: "eyepatch" },
-      Word{ category: "accessory", text: "hairtie" },
-      Word{ category: "accessory", text: "handbag" },
-      Word{ category: "accessory", text: "handkerchief" },
-      Word{ category: "accessory", text: "headband" },
-      Word{ category: "accessory", text: "kneepads" },
-      Word{ category: "accessory", text: "necklace" },
-      Word{ category: "accessory", text: "nosering" },
-      Word{ category: "accessory", text: "partyhat" },
-      Word{ category: "accessory", text: "shoelace" },
-      Word{ category: "accessory", text: "umbrella" },
-      Word{ category: "anatomy", text: "backbone" },
-      Word{ category: "anatomy", text: "eyeball" },
-      Word{ category: "anatomy", text: "eyebrow" },
-      Word{ category: "anatomy", text: "eyelash" },
-      Word{ category: "anatomy", text: "forehead" },
-      Word{ category: "anatomy", text: "kneecap" },
-      Word{ category: "anatomy", text: "knuckle" },
-      Word{ category: "anatomy", text: "mustache" },
-      Word{ category: "anatomy", text: "nosehair" },
-      Word{ category: "anatomy", text: "shoulder" },
-      Word{ category: "anatomy", text: "skeleton" },
-      Word{ category: "anatomy", text: "stomach" },
-      Word{ category: "anatomy", text: "tentacle" },
-      Word{ category: "anatomy", text: "unibrow" },
-      Word{ category: "anatomy", text: "whiskers" },
-      Word{ category: "animal", text: "aardvark" },
-      Word{ category: "animal", text: "alpaca" },
-      Word{ category: "animal", text: "ant" },
-      Word{ category: "animal", text: "anteater" },
-      Word{ category: "animal", text: "armadillo" },
-      Word{ category: "animal", text: "baboon" },
-      Word{ category: "animal", text: "badger" },
-      Word{ category: "animal", text: "bat" },
-      Word{ category: "animal", text: "bear" },
-      Word{ category: "animal", text: "beaver" },
-      Word{ category: "animal", text: "bee" },
-      Word{ category: "animal", text: "bird" },
-      Word{ category: "animal", text: "buffalo" },
-      Word{ category: "animal", text: "bull" },
-      Word{ category: "animal", text: "calf" },
-      Word{ category: "animal", text: "camel" },
-      Word{ category: "animal", text: "cat" },
-      Word{ category: "animal", text: "caterpillar" },
-      Word{ category: "animal", text: "catfish" },
-      Word{ category: "animal", text: "chameleon" },
-      Word{ category: "animal", text: "cheetah" },
-      Word{ category: "animal", text: "chicken" },
-      Word{ category: "animal", text: "chihuahua " },
-      Word{ category: "animal", text: "chimpanzee" },
-      Word{ category: "animal", text: "cicada " },
-      Word{ category: "animal", text: "cockroach" },
-      Word{ category: "animal", text: "cow" },
-      Word{ category: "animal", text: "crab" },
-      Word{ category: "animal", text: "crane" },
-      Word{ category: "animal", text: "cricket" },
-      Word{ category: "animal", text: "crocodile" },
-      Word{ category: "animal", text: "deer" },
-      Word{ category: "animal", text: "dinosaur" },
-      Word{ category: "animal", text: "dog" },
-      Word{ category: "animal", text: "dolphin" },
-      Word{ category: "animal", text: "donkey" },
-      Word{ category: "animal", text: "dragonfly" },
-      Word{ category: "animal", text: "duck" },
-      Word{ category: "animal", text: "eagle" },
-      Word{ category: "animal", text: "elephant" },
-      Word{ category: "animal", text: "firefly" },
-      Word{ category: "animal", text: "fish" },
-      Word{ category: "animal", text: "flamingo" },
-      Word{ category: "animal", text: "fly" },
-      Word{ category: "animal", text: "fox" },
-      Word{ category: "animal", text: "frog" },
-      Word{ category: "animal", text: "giraffe" },
-      Word{ category: "animal", text: "goat" },
-      Word{ category: "animal", text: "goldfish" },
-      Word{ category: "animal", text: "goose" },
-      Word{ category: "animal", text: "gorilla" },
-      Word{ category: "animal", text: "grasshopper" },
-      Word{ category: "animal", text: "hamster" },
-      Word{ category: "animal", text: "hippopotamus" },
-      Word{ category: "animal", text: "horse" },
-      Word{ category: "animal", text: "hyena" },
-      Word{ category: "animal", text: "jaguar" },
-      Word{ category: "animal", text: "kangaroo" },
-      Word{ category: "animal", text: "kitten" },
-      Word{ category: "animal", text: "ladybug" },
-      Word{ category: "animal", text: "leopard" },
-      Word{ category: "animal", text: "lion" },
-      Word{ category: "animal", text: "lizard" },
-      Word{ category: "animal", text: "llama" },
-      Word{ category: "animal", text: "lobster" },
-      Word{ category: "animal", text: "mammoth" },
-      Word{ category: "animal", text: "monkey" },
-      Word{ category: "animal", text: "moose" },
-      Word{ category: "animal", text: "mosquito" },
-      Word{ category: "animal", text: "mouse" },
-      Word{ category: "animal", text: "narwhal" },
-      Word{ category: "animal", text: "octopus" },
-      Word{ category: "animal", text: "ostrich" },
-      Word{ category: "animal", text: "otter" },
-      Word{ category: "animal", text: "owl" },
-      Word{ category: "animal", text: "oyster" },
-      Word{ category: "animal", text: "panda" },
-      Word{ category: "animal", text: "pangolin" },
-      Word{ category: "animal", text: "panther" },
-      Word{ category: "animal", text: "parrot" },
-      Word{ category: "animal", text: "peacock" },
-      Word{ category: "animal", text: "pelican" },
-      Word{ category: "animal", text: "penguin" },
-      Word{ category: "animal", text: "pig" },
-      Word{ category: "animal", text: "pigeon" },
-      Word{ category: "animal", text: "pitbull" },
-      Word{ category: "animal", text: "platypus" },
-      Word{ category: "animal", text: "poodle" },
-      Word{ category: "animal", text: "porcupine" },
-      Word{ category: "animal", text: "puppy" },
-      Word{ category: "animal", text: "rabbit" },
-      Word{ category: "animal", text: "raccoon" },
-      Word{ category: "animal", text: "rat" },
-      Word{ category: "animal", text: "reindeer" },
-      Word{ category: "animal", text: "reptile" },
-      Word{ category: "animal", text: "rhinoceros" },
-      Word{ category: "animal", text: "rooster" },
-      Word{ category: "animal", text: "scorpion" },
-      Word{ category: "animal", text: "seagull" },
-      Word{ category: "animal", text: "seahorse" },
-      Word{ category: "animal", text: "seal" },
-      Word{ category: "animal", text: "shark" },
-      Word{ category: "animal", text: "sheep" },
-      Word{ category: "animal", text: "shrimp" },
-      Word{ category: "animal", text: "skunk" },
-      Word{ category: "animal", text: "snail" },
-      Word{ category: "animal", text: "snake" },
-      Word{ category: "animal", text: "spider" },
-      Word{ category: "animal", text: "squid" },
-      Word{ category: "animal", text: "squirrel" },
-      Word{ category: "animal", text: "starfish" },
-      Word{ category: "animal", text: "stork" },
-      Word{ category: "animal", text: "swan" },
-      Word{ category: "animal", text: "tadpole" },
-      Word{ category: "animal", text: "tiger" },
-      Word{ category: "animal", text: "toad" },
-      Word{ category: "animal", text: "tortoise" },
-      Word{ category: "animal", text: "tropical fish" },
-      Word{ category: "animal", text: "tuna" },
-      Word{ category: "animal", text: "turkey" },
-      Word{ category: "animal", text: "turtle" },
-      Word{ category: "animal", text: "vulture" },
-      Word{ category: "animal", text: "walrus" },
-      Word{ category: "animal", text: "whale" },
-      Word{ category: "animal", text: "wolf" },
-      Word{ category: "animal", text: "wombat" },
-      Word{ category: "animal", text: "worm" },
-      Word{ category: "animal", text: "zebra" },
-      Word{ category: "clothing", text: "bandana" },
-      Word{ category: "clothing", text: "belt" },
-      Word{ category: "clothing", text: "bikini" },
-      Word{ category: "clothing", text: "boots" },
-      Word{ category: "clothing", text: "bowtie" },
-      Word{ category: "clothing", text: "cap" },
-      Word{ category: "clothing", text: "chasity belt" },
-      Word{ category: "clothing", text: "coat" },
-      Word{ category: "clothing", text: "cowboy hat" },
-      Word{ category: "clothing", text: "dress" },
-      Word{ category: "clothing", text: "earmuffs" },
-      Word{ category: "clothing", text: "eyepatch" },
-      Word{ category: "clothing", text: "glasses" },
-      Word{ category: "clothing", text: "gloves" },
-      Word{ category: "clothing", text: "hairtie" },
-      Word{ category: "clothing", text: "hat" },
-      Word{ category: "clothing", text: "headband" },
-      Word{ category: "clothing", text: "hoodie" },
-      Word{ category: "clothing", text: "jacket" },
-      Word{ category: "clothing", text: "jeans" },
-      Word{ category: "clothing", text: "kneepads" },
-      Word{ category: "clothing", text: "mittens" },
-      Word{ category: "clothing", text: "necklace" },
-      Word{ category: "clothing", text: "overalls" },
-      Word{ category: "clothing", text: "pajamas" },
-      Word{ category: "clothing", text: "pantsuit" },
-      Word{ category: "clothing", text: "partyhat" },
-      Word{ category: "clothing", text: "raincoat" },
-      Word{ category: "clothing", text: "sandals" },
-      Word{ category: "clothing", text: "scarf" },
-      Word{ category: "clothing", text: "shirt" },
-      Word{ category: "clothing", text: "shoes" },
-      Word{ category: "clothing", text: "skirt" },
-      Word{ category: "clothing", text: "slippers" },
-      Word{ category: "clothing", text: "sneakers" },
-      Word{ category: "clothing", text: "socks" },
-      Word{ category: "clothing", text: "sombrero" },
-      Word{ category: "clothing", text: "stockings" },
-      Word{ category: "clothing", text: "suit" },
-      Word{ category: "clothing", text: "sweater" },
-      Word{ category: "clothing", text: "sweatshirt" },
-      Word{ category: "clothing", text: "swimsuit" },
-      Word{ category: "clothing", text: "t-shirt" },
-      Word{ category: "clothing", text: "tie" },
-      Word{ category: "clothing", text: "tophat" },
-      Word{ category: "clothing", text: "trousers" },
-      Word{ category: "clothing", text: "underpants" },
-      Word{ category: "clothing ", text: "tophat" },
-      Word{ category: "computing", text: "computer" },
-      Word{ category: "computing", text: "facebook" },
-      Word{ category: "computing", text: "firewall" },
-      Word{ category: "computing", text: "hashtag" },
-      Word{ category: "computing", text: "keyboard" },
-      Word{ category: "computing", text: "macbook" },
-      Word{ category: "computing", text: "password" },
-      Word{ category: "computing", text: "twitter" },
-      Word{ category: "fairytale", text: "aladdin" },
-      Word{ category: "fairytale", text: "ali baba and the forty thieves" },
-      Word{ category: "fairytale", text: "beauty and the beast" },
-      Word{ category: "fairytale", text: "cinderella" },
-      Word{ category: "fairytale", text: "goldilocks and the three bears" },
-      Word{ category: "fairytale", text: "hansel and gretel" },
-      Word{ category: "fairytale", text: "puss in boots" },
-      Word{ category: "fairytale", text: "rapunzel" },
-      Word{ category: "fairytale", text: "rumpelstiltskin" },
-      Word{ category: "fairytale", text: "sinbad" },
-      Word{ category: "fairytale", text: "sleeping beauty" },
-      Word{ category: "fairytale", text: "snow white" },
-      Word{ category: "fairytale", text: "the emperor's new clothes" },
-      Word{ category: "fairytale", text: "the fisherman and the jinni" },
-      Word{ category: "fairytale", text: "the frog prince" },
-      Word{ category: "fairytale", text: "the golden fish" },
-      Word{ category: "fairytale", text: "the golden goose" },
-      Word{ category: "fairytale", text: "the little mermaid" },
-      Word{ category: "fairytale", text: "the princess and the pea" },
-      Word{ category: "fairytale", text: "the steadfast tin soldier" },
-      Word{ category: "fairytale", text: "the three little pigs" },
-      Word{ category: "fairytale", text: "the tin soldier" },
-      Word{ category: "fairytale", text: "the tinderbox" },
-      Word{ category: "fairytale", text: "the ugly duckling" },
-      Word{ category: "fairytale", text: "thumbelina" },
-      Word{ category: "fairytale", text: "thumbelina" },
-      Word{ category: "fairytale", text: "thumbelina" },
-      Word{ category: "family", text: "aunt" },
-      Word{ category: "family", text: "brother" },
-      Word{ category: "family", text: "children" },
-      Word{ category: "family", text: "cousin" },
-      Word{ category: "family", text: "father" },
-      Word{ category: "family", text: "father in law" },
-      Word{ category: "family", text: "god father" },
-      Word{ category: "family", text: "god mother" },
-      Word{ category: "family", text: "granddaugther" },
-      Word{ category: "family", text: "grandma" },
-      Word{ category: "family", text: "grandpa" },
-      Word{ category: "family", text: "grandson" },
-      Word{ category: "family", text: "husband" },
-      Word{ category: "family", text: "mother" },
-      Word{ category: "family", text: "mother in law" },
-      Word{ category: "family", text: "nephew" },
-      Word{ category: "family", text: "niece" },
-      Word{ category: "family", text: "parents" },
-      Word{ category: "family", text: "sister" },
-      Word{ category: "family", text: "step brother" },
-      Word{ category: "family", text: "step father" },
-      Word{ category: "family", text: "step mother" },
-      Word{ category: "family", text: "step sister" },
-      Word{ category: "family", text: "uncle" },
-      Word{ category: "family", text: "wife" },
-      Word{ category: "famous landmark", text: "acropolis" },
-      Word{ category: "famous landmark", text: "big ben" },
-      Word{ category: "famous landmark", text: "brandenburg gate" },
-      Word{ category: "famous landmark", text: "capitol hill" },
-      Word{ category: "famous landmark", text: "christ the redeemer statue" },
-      Word{ category: "famous landmark", text: "colosseum" },
-      Word{ category: "famous landmark", text: "easter island" },
-      Word{ category: "famous landmark", text: "empire state building" },
-      Word{ category: "famous landmark", text: "fontana de trevi" },
-      Word{ category: "famous landmark", text: "golden gate bridge " },
-      Word{ category: "famous landmark", text: "grand canyon" },
-      Word{ category: "famous landmark", text: "leaning tower of pisa" },
-      Word{ category: "famous landmark", text: "loch ness" },
-      Word{ category: "famous landmark", text: "mount rushmore" },
-      Word{ category: "famous landmark", text: "niagra falls" },
-      Word{ category: "famous landmark", text: "statue of liberty" },
-      Word{ category: "famous landmark", text: "stonehenge" },
-      Word{ category: "famous landmark", text: "sydney opera house" },
-      Word{ category: "famous landmark", text: "the eiffel tower" },
-      Word{ category: "famous landmark", text: "the forbidden city" },
-      Word{ category: "famous landmark", text: "the great chinese wall" },
-      Word{ category: "famous landmark", text: "the hollywood sign " },
-      Word{ category: "famous landmark", text: "the kremlin" },
-      Word{ category: "famous landmark", text: "the little mermaid" },
-      Word{ category: "famous landmark", text: "the louvre " },
-      Word{ category: "famous landmark", text: "the pyramids" },
-      Word{ category: "famous landmark", text: "the sphinx" },
-      Word{ category: "famous landmark", text: "the taj mahal" },
-      Word{ category: "famous person", text: "angelina jolie" },
-      Word{ category: "famous person", text: "beckham" },
-      Word{ category: "famous person", text: "bob dylan" },
-      Word{ category: "famous person", text: "brad pitt" },
-      Word{ category: "famous person", text: "bruce lee" },
-      Word{ category: "famous person", text: "colbert" },
-      Word{ category: "famous person", text: "donald trump" },
-      Word{ category: "famous person", text: "eminem" },
-      Word{ category: "famous person", text: "gandhi" },
-      Word{ category: "famous person", text: "geronimo" },
-      Word{ category: "famous person", text: "greenday" },
-      Word{ category: "famous person", text: "jay leno" },
-      Word{ category: "famous person", text: "justin bieber" },
-      Word{ category: "famous person", text: "kate moss" },
-      Word{ category: "famous person", text: "lady gaga" },
-      Word{ category: "famous person", text: "lil wayne" },
-      Word{ category: "famous person", text: "lincoln" },
-      Word{ category: "famous person", text: "ludacris" },
-      Word{ category: "famous person", text: "madonna" },
-      Word{ category: "famous person", text: "mc hammer" },
-      Word{ category: "famous person", text: "picasso" },
-      Word{ category: "famous person", text: "pitbull" },
-      Word{ category: "famous person", text: "rihanna" },
-      Word{ category: "famous person", text: "tom hanks" },
-      Word{ category: "famous person", text: "van gogh" },
-      Word{ category: "famous person", text: "vincent van gogh" },
-      Word{ category: "festivity", text: "bar mitzvah" },
-      Word{ category: "festivity", text: "birthday" },
-      Word{ category: "festivity", text: "black friday" },
-      Word{ category: "festivity", text: "christmas" },
-      Word{ category: "festivity", text: "columbus day" },
-      Word{ category: "festivity", text: "divali" },
-      Word{ category: "festivity", text: "día de muertos" },
-      Word{ category: "festivity", text: "easter" },
-      Word{ category: "festivity", text: "election" },
-      Word{ category: "festivity", text: "festivus" },
-      Word{ category: "festivity", text: "graduation" },
-      Word{ category: "festivity", text: "groundhog day" },
-      Word{ category: "festivity", text: "halloween" },
-      Word{ category: "festivity", text: "hanukkah" },
-      Word{ category: "festivity", text: "independence day" },
-      Word{ category: "festivity", text: "kwanzaa" },
-      Word{ category: "festivity", text: "mother`s day" },
-      Word{ category: "festivity", text: "new year`s eve" },
-      Word{ category: "festivity", text: "octoberfest" },
-      Word{ category: "festivity", text: "passover" },
-      Word{ category: "festivity", text: "ramadan" },
-      Word{ category: "festivity", text: "st. patrick`s day" },
-      Word{ category: "festivity", text: "thanks giving" },
-      Word{ category: "festivity", text: "valentine`s day" },
-      Word{ category: "festivity", text: "wedding" },
-      Word{ category: "film genres", text: "action" },
-      Word{ category: "film genres", text: "animation" },
-      Word{ category: "film genres", text: "comedy" },
-      Word{ category: "film genres", text: "disasters" },
-      Word{ category: "film genres", text: "documentry" },
-      Word{ category: "film genres", text: "gangster" },
-      Word{ category: "film genres", text: "horror" },
-      Word{ category: "film genres", text: "mockumentry" },
-      Word{ category: "film genres", text: "musical" },
-      Word{ category: "film genres", text: "rom-com" },
-      Word{ category: "film genres", text: "sci-fi" },
-      Word{ category: "film genres", text: "slapstick" },
-      Word{ category: "film genres", text: "sport" },
-      Word{ category: "film genres", text: "thriller" },
-      Word{ category: "film genres", text: "war" },
-      Word{ category: "film genres", text: "western" },
-      Word{ category: "film genres", text: "zombies" },
-      Word{ category: "food", text: "bacon" },
-      Word{ category: "food", text: "baguette" },
-      Word{ category: "food", text: "barbeque" },
-      Word{ category: "food", text: "beans" },
-      Word{ category: "food", text: "beer" },
-      Word{ category: "food", text: "burrito" },
-      Word{ category: "food", text: "cake" },
-      Word{ category: "food", text: "cereal" },
-      Word{ category: "food", text: "champagne" },
-      Word{ category: "food", text: "cheese" },
-      Word{ category: "food", text: "chicken wings" },
-      Word{ category: "food", text: "coffee" },
-      Word{ category: "food", text: "corndog" },
-      Word{ category: "food", text: "croissant" },
-      Word{ category: "food", text: "cupcake" },
-      Word{ category: "food", text: "eggroll" },
-      Word{ category: "food", text: "espresso" },
-      Word{ category: "food", text: "fast food" },
-      Word{ category: "food", text: "fish and chips" },
-      Word{ category: "food", text: "frosting" },
-      Word{ category: "food", text: "hambone" },
-      Word{ category: "food", text: "hamburger" },
-      Word{ category: "food", text: "hamwich" },
-      Word{ category: "food", text: "hot sauce" },
-      Word{ category: "food", text: "junk food" },
-      Word{ category: "food", text: "kebab" },
-      Word{ category: "food", text: "ketchup" },
-      Word{ category: "food", text: "lasagna" },
-      Word{ category: "food", text: "lemonade" },
-      Word{ category: "food", text: "macaroni" },
-      Word{ category: "food", text: "martini" },
-      Word{ category: "food", text: "mashed potatoes" },
-      Word{ category: "food", text: "meatballs" },
-      Word{ category: "food", text: "milk" },
-      Word{ category: "food", text: "mushroom" },
-      Word{ category: "food", text: "mustard" },
-      Word{ category: "food", text: "noodles" },
-      Word{ category: "food", text: "oatmeal" },
-      Word{ category: "food", text: "pancake" },
-      Word{ category: "food", text: "pasta" },
-      Word{ category: "food", text: "pie" },
-      Word{ category: "food", text: "pizza" },
-      Word{ category: "food", text: "popcorn" },
-      Word{ category: "food", text: "pretzel" },
-      Word{ category: "food", text: "pumpkin" },
-      Word{ category: "food", text: "salad" },
-      Word{ category: "food", text: "sandwich" },
-      Word{ category: "food", text: "sausage" },
-      Word{ category: "food", text: "seafood" },
-      Word{ category: "food", text: "smoothie" },
-      Word{ category: "food", text: "snowcone" },
-      Word{ category: "food", text: "soy sauce" },
-      Word{ category: "food", text: "sushi" },
-      Word{ category: "food", text: "tea" },
-      Word{ category: "food", text: "tequila" },
-      Word{ category: "food", text: "waffles" },
-      Word{ category: "food", text: "whiskey" },
-      Word{ category: "furniture", text: "barstool" },
-      Word{ category: "furniture", text: "bathtub" },
-      Word{ category: "furniture", text: "bed" },
-      Word{ category: "furniture", text: "bookcase" },
-      Word{ category: "furniture", text: "bunkbed" },
-      Word{ category: "furniture", text: "cabinet" },
-      Word{ category: "furniture", text: "chair" },
-      Word{ category: "furniture", text: "dresser" },
-      Word{ category: "furniture", text: "waterbed" },
-      Word{ category: "games", text: "balloon" },
-      Word{ category: "games", text: "checkers" },
-      Word{ category: "games", text: "dreidel" },
-      Word{ category: "games", text: "gameboy" },
-      Word{ category: "games", text: "hulahoop" },
-      Word{ category: "games", text: "joystick" },
-      Word{ category: "games", text: "monopoly" },
-      Word{ category: "games", text: "pinball" },
-      Word{ category: "games", text: "pinwheel" },
-      Word{ category: "games", text: "scrabble" },
-      Word{ category: "games", text: "swingset" },
-      Word{ category: "games", text: "yahtzee" },
-      Word{ category: "geography", text: "america" },
-      Word{ category: "geography", text: "atlanta" },
-      Word{ category: "geography", text: "bahamas" },
-      Word{ category: "geography", text: "england" },
-      Word{ category: "geography", text: "finland" },
-      Word{ category: "geography", text: "florida" },
-      Word{ category: "geography", text: "germany" },
-      Word{ category: "geography", text: "holland" },
-      Word{ category: "geography", text: "hongkong" },
-      Word{ category: "geography", text: "ireland" },
-      Word{ category: "geography", text: "jamaica" },
-      Word{ category: "geography", text: "new-york" },
-      Word{ category: "geography", text: "orlando" },
-      Word{ category: "geography", text: "scotland" },
-      Word{ category: "location", text: "airport" },
-      Word{ category: "location", text: "apartment building" },
-      Word{ category: "location", text: "bank" },
-      Word{ category: "location", text: "bar" },
-      Word{ category: "location", text: "barber shop" },
-      Word{ category: "location", text: "barn" },
-      Word{ category: "location", text: "beach" },
-      Word{ category: "location", text: "book store" },
-      Word{ category: "location", text: "bowling alley" },
-      Word{ category: "location", text: "bus stop" },
-      Word{ category: "location", text: "casino" },
-      Word{ category: "location", text: "castle" },
-      Word{ category: "location", text: "cathedral" },
-      Word{ category: "location", text: "cemetery" },
-      Word{ category: "location", text: "church" },
-      Word{ category: "location", text: "circus" },
-      Word{ category: "location", text: "convenience store" },
-      Word{ category: "location", text: "courthouse" },
-      Word{ category: "location", text: "department store" },
-      Word{ category: "location", text: "desert" },
-      Word{ category: "location", text: "disco" },
-      Word{ category: "location", text: "dungeon" },
-      Word{ category: "location", text: "fire station" },
-      Word{ category: "location", text: "garden" },
-      Word{ category: "location", text: "gas station" },
-      Word{ category: "location", text: "golf course" },
-      Word{ category: "location", text: "hospital" },
-      Word{ category: "location", text: "house" },
-      Word{ category: "location", text: "library" },
-      Word{ category: "location", text: "lighthouse" },
-      Word{ category: "location", text: "mall" },
-      Word{ category: "location", text: "mansion" },
-      Word{ category: "location", text: "movie theater" },
-      Word{ category: "location", text: "museum" },
-      Word{ category: "location", text: "office building" },
-      Word{ category: "location", text: "opera house" },
-      Word{ category: "location", text: "orchard" },
-      Word{ category: "location", text: "palace" },
-      Word{ category: "location", text: "petshop" },
-      Word{ category: "location", text: "police station" },
-      Word{ category: "location", text: "post office" },
-      Word{ category: "location", text: "prison" },
-      Word{ category: "location", text: "pub" },
-      Word{ category: "location", text: "restaurant" },
-      Word{ category: "location", text: "safari" },
-      Word{ category: "location", text: "saw mill" },
-      Word{ category: "location", text: "school" },
-      Word{ category: "location", text: "skyscraper" },
-      Word{ category: "location", text: "supermarket" },
-      Word{ category: "location", text: "swimming pool" },
-      Word{ category: "location", text: "temple" },
-      Word{ category: "location", text: "theater" },
-      Word{ category: "location", text: "theme park" },
-      Word{ category: "location", text: "train station" },
-      Word{ category: "location", text: "vet clinic" },
-      Word{ category: "location", text: "zoo" },
-      Word{ category: "medical", text: "crutches" },
-      Word{ category: "medical", text: "dandruff" },
-      Word{ category: "medical", text: "dentures" },
-      Word{ category: "medical", text: "firstaid" },
-      Word{ category: "medical", text: "headache" },
-      Word{ category: "medical", text: "hospital" },
-      Word{ category: "medical", text: "insomnia" },
-      Word{ category: "medical", text: "medicine" },
-      Word{ category: "medical", text: "papercut" },
-      Word{ category: "medical", text: "seasick" },
-      Word{ category: "medical", text: "sunburn" },
-      Word{ category: "medical", text: "vaccine" },
-      Word{ category: "medical", text: "vitamin" },
-      Word{ category: "movies", text: "a clockwork orange" },
-      Word{ category: "movies", text: "alien" },
-      Word{ category: "movies", text: "armagedon" },
-      Word{ category: "movies", text: "austin powers" },
-      Word{ category: "movies", text: "avengers" },
-      Word{ category: "movies", text: "back to the future" },
-      Word{ category: "movies", text: "beetlejuice" },
-      Word{ category: "movies", text: "braveheart" },
-      Word{ category: "movies", text: "die hard" },
-      Word{ category: "movies", text: "e.t." },
-      Word{ category: "movies", text: "fargo" },
-      Word{ category: "movies", text: "finding nemo" },
-      Word{ category: "movies", text: "forrest gump" },
-      Word{ category: "movies", text: "frozen" },
-      Word{ category: "movies", text: "gladiator" },
-      Word{ category: "movies", text: "golden eye" },
-      Word{ category: "movies", text: "gone with the wind" },
-      Word{ category: "movies", text: "groundhog day" },
-      Word{ category: "movies", text: "home alone" },
-      Word{ category: "movies", text: "inception" },
-      Word{ category: "movies", text: "indiana jones" },
-      Word{ category: "movies", text: "inglourious basterds" },
-      Word{ category: "movies", text: "jaws" },
-      Word{ category: "movies", text: "jurassic park" },
-      Word{ category: "movies", text: "king kong" },
-      Word{ category: "movies", text: "lion king" },
-      Word{ category: "movies", text: "mary poppins" },
-      Word{ category: "movies", text: "minority report" },
-      Word{ category: "movies", text: "mission impossible" },
-      Word{ category: "movies", text: "monty python and the holy grail" },
-      Word{ category: "movies", text: "princess bride" },
-      Word{ category: "movies", text: "pulp fiction" },
-      Word{ category: "movies", text: "robocop" },
-      Word{ category: "movies", text: "rocky" },
-      Word{ category: "movies", text: "rocky horror picture show" },
-      Word{ category: "movies", text: "saving private ryan" },
-      Word{ category: "movies", text: "scarface" },
-      Word{ category: "movies", text: "shrek" },
-      Word{ category: "movies", text: "star wars" },
-      Word{ category: "movies", text: "terminator" },
-      Word{ category: "movies", text: "the good, the bad and the ugly" },
-      Word{ category: "movies", text: "the green mile" },
-      Word{ category: "movies", text: "the lord of the rings" },
-      Word{ category: "movies", text: "the matrix" },
-      Word{ category: "movies", text: "the shawshank redemption" },
-      Word{ category: "movies", text: "the social network" },
-      Word{ category: "movies", text: "the wizard of oz" },
-      Word{ category: "movies", text: "titanic" },
-      Word{ category: "movies", text: "toy story" },
-      Word{ category: "movies", text: "west side story" },
-      Word{ category: "movies", text: "wonder woman" },
-      Word{ category: "movies", text: "x-men" },
-      Word{ category: "music band", text: "ac/dc" },
-      Word{ category: "music band", text: "adele" },
-      Word{ category: "music band", text: "beatles" },
-      Word{ category: "music band", text: "beyonce" },
-      Word{ category: "music band", text: "billie eilish" },
-      Word{ category: "music band", text: "bon jovi" },
-      Word{ category: "music band", text: "bruno mars" },
-      Word{ category: "music band", text: "childish gambino" },
-      Word{ category: "music band", text: "coldplay" },
-      Word{ category: "music band", text: "daftpunk" },
-      Word{ category: "music band", text: "david bowie" },
-      Word{ category: "music band", text: "dr. dre" },
-      Word{ category: "music band", text: "drake" },
-      Word{ category: "music band", text: "ed shiran" },
-      Word{ category: "music band", text: "elton john" },
-      Word{ category: "music band", text: "eminem" },
-      Word{ category: "music band", text: "guns and roses" },
-      Word{ category: "music band", text: "jay z" },
-      Word{ category: "music band", text: "justin bieber" },
-      Word{ category: "music band", text: "justin timberlake" },
-      Word{ category: "music band", text: "kanye west" },
-      Word{ category: "music band", text: "katy perry" },
-      Word{ category: "music band", text: "lady gaga" },
-      Word{ category: "music band", text: "led zeppelin" },
-      Word{ category: "music band", text: "mariah carey" },
-      Word{ category: "music band", text: "michael jackson" },
-      Word{ category: "music band", text: "muse" },
-      Word{ category: "music band", text: "nicki minaj" },
-      Word{ category: "music band", text: "nirvana" },
-      Word{ category: "music band", text: "pink floyd" },
-      Word{ category: "music band", text: "pitbull" },
-      Word{ category: "music band", text: "prince" },
-      Word{ category: "music band", text: "queen" },
-      Word{ category: "music band", text: "rihana" },
-      Word{ category: "music band", text: "skrillex" },
-      Word{ category: "music band", text: "snoop dogg" },
-      Word{ category: "music band", text: "taylor swift" },
-      Word{ category: "music band", text: "the black eyed peas" },
-      Word{ category: "music band", text: "tupac shakur" },
-      Word{ category: "music band", text: "u2" },
-      Word{ category: "music genre", text: "blues" },
-      Word{ category: "music genre", text: "classical" },
-      Word{ category: "music genre", text: "country" },
-      Word{ category: "music genre", text: "disco" },
-      Word{ category: "music genre", text: "dubstep" },
-      Word{ category: "music genre", text: "elevator music" },
-      Word{ category: "music genre", text: "grunge" },
-      Word{ category: "music genre", text: "heavy metal" },
-      Word{ category: "music genre", text: "hip hop" },
-      Word{ category: "music genre", text: "house" },
-      Word{ category: "music genre", text: "j-pop" },
-      Word{ category: "music genre", text: "jazz" },
-      Word{ category: "music genre", text: "pop" },
-      Word{ category: "music genre", text: "punk" },
-      Word{ category: "music genre", text: "r&b" },
-      Word{ category: "music genre", text: "rap" },
-      Word{ category: "music genre", text: "reggae" },
-      Word{ category: "music genre", text: "rock" },
-      Word{ category: "music genre", text: "swing" },
-      Word{ category: "music genre", text: "techno" },
-      Word{ category: "musical instrument", text: "accordion" },
-      Word{ category: "musical instrument", text: "bagpipes" },
-      Word{ category: "musical instrument", text: "banjo" },
-      Word{ category: "musical instrument", text: "bass guitar" },
-      Word{ category: "musical instrument", text: "bugle" },
-      Word{ category: "musical instrument", text: "castanets" },
-      Word{ category: "musical instrument", text: "cello" },
-      Word{ category: "musical instrument", text: "clarinet" },
-      Word{ category: "musical instrument", text: "cowbell" },
-      Word{ category: "musical instrument", text: "cymbal" },
-      Word{ category: "musical instrument", text: "didgeridoo" },
-      Word{ category: "musical instrument", text: "drums" },
-      Word{ category: "musical instrument", text: "electric guitar" },
-      Word{ category: "musical instrument", text: "fiddle" },
-      Word{ category: "musical instrument", text: "flute" },
-      Word{ category: "musical instrument", text: "gong" },
-      Word{ category: "musical instrument", text: "harmonica" },
-      Word{ category: "musical instrument", text: "harp" },
-      Word{ category: "musical instrument", text: "horn" },
-      Word{ category: "musical instrument", text: "jukebox" },
-      Word{ category: "musical instrument", text: "kazoo" },
-      Word{ category: "musical instrument", text: "lute" },
-      Word{ category: "musical instrument", text: "maraca" },
-      Word{ category: "musical instrument", text: "marimba" },
-      Word{ category: "musical instrument", text: "oboe" },
-      Word{ category: "musical instrument", text: "ocarina" },
-      Word{ category: "musical instrument", text: "piano" },
-      Word{ category: "musical instrument", text: "piccolo" },
-      Word{ category: "musical instrument", text: "saxophone" },
-      Word{ category: "musical instrument", text: "sitar" },
-      Word{ category: "musical instrument", text: "tambourine" },
-      Word{ category: "musical instrument", text: "triangle" },
-      Word{ category: "musical instrument", text: "trombone" },
-      Word{ category: "musical instrument", text: "trumpet" },
-      Word{ category: "musical instrument", text: "tuba" },
-      Word{ category: "musical instrument", text: "ukulele" },
-      Word{ category: "musical instrument", text: "violin" },
-      Word{ category: "musical instrument", text: "washboard" },
-      Word{ category: "musical instrument", text: "whistle" },
-      Word{ category: "musical instrument", text: "xylophone" },
-      Word{ category: "mythology", text: "amazon warrior" },
-      Word{ category: "mythology", text: "basilisk" },
-      Word{ category: "mythology", text: "bigfoot" },
-      Word{ category: "mythology", text: "centaur" },
-      Word{ category: "mythology", text: "chimera" },
-      Word{ category: "mythology", text: "cyclop" },
-      Word{ category: "mythology", text: "dragon" },
-      Word{ category: "mythology", text: "dryad" },
-      Word{ category: "mythology", text: "elf" },
-      Word{ category: "mythology", text: "fairy" },
-      Word{ category: "mythology", text: "genie" },
-      Word{ category: "mythology", text: "ghost" },
-      Word{ category: "mythology", text: "ghoul" },
-      Word{ category: "mythology", text: "giant" },
-      Word{ category: "mythology", text: "goblin" },
-      Word{ category: "mythology", text: "golem" },
-      Word{ category: "mythology", text: "griffin" },
-      Word{ category: "mythology", text: "imp" },
-      Word{ category: "mythology", text: "lizard people" },
-      Word{ category: "mythology", text: "magic carpet" },
-      Word{ category: "mythology", text: "manticore" },
-      Word{ category: "mythology", text: "medusa" },
-      Word{ category: "mythology", text: "mermaid" },
-      Word{ category: "mythology", text: "minotaur" },
-      Word{ category: "mythology", text: "minotaur" },
-      Word{ category: "mythology", text: "mummy" },
-      Word{ category: "mythology", text: "nymph" },
-      Word{ category: "mythology", text: "ogre" },
-      Word{ category: "mythology", text: "pegasus" },
-      Word{ category: "mythology", text: "phoenix" },
-      Word{ category: "mythology", text: "satyr" },
-      Word{ category: "mythology", text: "sphinx" },
-      Word{ category: "mythology", text: "troll" },
-      Word{ category: "mythology", text: "unicorn" },
-      Word{ category: "mythology", text: "vampire" },
-      Word{ category: "mythology", text: "werewolf" },
-      Word{ category: "mythology", text: "zombie" },
-      Word{ category: "plant", text: "avocado" },
-      Word{ category: "plant", text: "bamboo" },
-      Word{ category: "plant", text: "banana" },
-      Word{ category: "plant", text: "broccoli" },
-      Word{ category: "plant", text: "cabbage" },
-      Word{ category: "plant", text: "cactus" },
-      Word{ category: "plant", text: "carrot" },
-      Word{ category: "plant", text: "celery" },
-      Word{ category: "plant", text: "cherry" },
-      Word{ category: "plant", text: "cilantro" },
-      Word{ category: "plant", text: "coconut" },
-      Word{ category: "plant", text: "cucumber" },
-      Word{ category: "plant", text: "eggplant" },
-      Word{ category: "plant", text: "garlic" },
-      Word{ category: "plant", text: "grapes" },
-      Word{ category: "plant", text: "lemon" },
-      Word{ category: "plant", text: "lime" },
-      Word{ category: "plant", text: "mango" },
-      Word{ category: "plant", text: "oak" },
-      Word{ category: "plant", text: "orange" },
-      Word{ category: "plant", text: "palmtree" },
-      Word{ category: "plant", text: "peaches" },
-      Word{ category: "plant", text: "persimmon" },
-      Word{ category: "plant", text: "pineapple" },
-      Word{ category: "plant", text: "pinecone" },
-      Word{ category: "plant", text: "pinetree" },
-      Word{ category: "plant", text: "potato" },
-      Word{ category: "plant", text: "primrose" },
-      Word{ category: "plant", text: "seaweed" },
-      Word{ category: "plant", text: "spinach" },
-      Word{ category: "plant", text: "tomato" },
-      Word{ category: "plant", text: "watermelon" },
-      Word{ category: "plant", text: "wheat" },
-      Word{ category: "plant", text: "zucchini" },
-      Word{ category: "pop culture", text: "aladdin" },
-      Word{ category: "pop culture", text: "aquaman" },
-      Word{ category: "pop culture", text: "asterix" },
-      Word{ category: "pop culture", text: "autobots" },
-      Word{ category: "pop culture", text: "avatar" },
-      Word{ category: "pop culture", text: "barbie" },
-      Word{ category: "pop culture", text: "batman" },
-      Word{ category: "pop culture", text: "beavis" },
-      Word{ category: "pop culture", text: "bowser" },
-      Word{ category: "pop culture", text: "catwoman" },
-      Word{ category: "pop culture", text: "conan the barbarian" },
-      Word{ category: "pop culture", text: "garfield" },
-      Word{ category: "pop culture", text: "hawkeye" },
-      Word{ category: "pop culture", text: "iron chef" },
-      Word{ category: "pop culture", text: "kermit" },
-      Word{ category: "pop culture", text: "legend of zelda" },
-      Word{ category: "pop culture", text: "metroid" },
-      Word{ category: "pop culture", text: "mickey mouse" },
-      Word{ category: "pop culture", text: "pikachu" },
-      Word{ category: "pop culture", text: "rick & morty" },
-      Word{ category: "pop culture", text: "riddler" },
-      Word{ category: "pop culture", text: "rugrats" },
-      Word{ category: "pop culture", text: "scooby doo" },
-      Word{ category: "pop culture", text: "simba" },
-      Word{ category: "pop culture", text: "splinter" },
-      Word{ category: "pop culture", text: "spongebob" },
-      Word{ category: "pop culture", text: "superman" },
-      Word{ category: "pop culture", text: "the simpsons" },
-      Word{ category: "pop culture", text: "twoface" },
-      Word{ category: "pop culture", text: "voldemort" },
-      Word{ category: "profession", text: "artist" },
-      Word{ category: "profession", text: "assassin" },
-      Word{ category: "profession", text: "barber" },
-      Word{ category: "profession", text: "butcher" },
-      Word{ category: "profession", text: "captain" },
-      Word{ category: "profession", text: "cashier" },
-      Word{ category: "profession", text: "chef" },
-      Word{ category: "profession", text: "clown" },
-      Word{ category: "profession", text: "cook" },
-      Word{ category: "profession", text: "cowboy" },
-      Word{ category: "profession", text: "cowgirl" },
-      Word{ category: "profession", text: "dentist" },
-      Word{ category: "profession", text: "dj" },
-      Word{ category: "profession", text: "doctor" },
-      Word{ category: "profession", text: "drummer" },
-      Word{ category: "profession", text: "farmer" },
-      Word{ category: "profession", text: "firefighter" },
-      Word{ category: "profession", text: "gangster" },
-      Word{ category: "profession", text: "gardener" },
-      Word{ category: "profession", text: "gymnast" },
-      Word{ category: "profession", text: "hacker" },
-      Word{ category: "profession", text: "janitor" },
-      Word{ category: "profession", text: "lawyer" },
-      Word{ category: "profession", text: "magician" },
-      Word{ category: "profession", text: "mailman" },
-      Word{ category: "profession", text: "milkman" },
-      Word{ category: "profession", text: "movie director" },
-      Word{ category: "profession", text: "opera singer" },
-      Word{ category: "profession", text: "painter" },
-      Word{ category: "profession", text: "pilot" },
-      Word{ category: "profession", text: "plumber" },
-      Word{ category: "profession", text: "policeman" },
-      Word{ category: "profession", text: "poolboy" },
-      Word{ category: "profession", text: "priest" },
-      Word{ category: "profession", text: "princess" },
-      Word{ category: "profession", text: "sheriff" },
-      Word{ category: "profession", text: "soldier" },
-      Word{ category: "profession", text: "surgeon" },
-      Word{ category: "profession", text: "teacher" },
-      Word{ category: "profession", text: "teacher" },
-      Word{ category: "profession", text: "waiter" },
-      Word{ category: "profession", text: "warrior" },
-      Word{ category: "profession", text: "window cleaner " },
-      Word{ category: "profession", text: "wrestler" },
-      Word{ category: "snacks", text: "brownie" },
-      Word{ category: "snacks", text: "cookie" },
-      Word{ category: "snacks", text: "doritos" },
-      Word{ category: "snacks", text: "icecream" },
-      Word{ category: "snacks", text: "lollipop" },
-      Word{ category: "snacks", text: "popsicle" },
-      Word{ category: "snacks", text: "skittles" },
-      Word{ category: "snacks", text: "sour candy" },
-      Word{ category: "snacks", text: "sour candy" },
-      Word{ category: "snacks", text: "twinkie" },
-      Word{ category: "social", text: "bromance" },
-      Word{ category: "social", text: "divorce" },
-      Word{ category: "social", text: "follower" },
-      Word{ category: "social", text: "frenemy" },
-      Word{ category: "social", text: "highfive" },
-      Word{ category: "social", text: "marriage" },
-      Word{ category: "sports", text: "archery" },
-      Word{ category: "sports", text: "baseball" },
-      Word{ category: "sports", text: "basketball" },
-      Word{ category: "sports", text: "bowling" },
-      Word{ category: "sports", text: "boxing" },
-      Word{ category: "sports", text: "cricket" },
-      Word{ category: "sports", text: "curling" },
-      Word{ category: "sports", text: "dumbbell" },
-      Word{ category: "sports", text: "fencing" },
-      Word{ category: "sports", text: "fishing" },
-      Word{ category: "sports", text: "football" },
-      Word{ category: "sports", text: "frisbee" },
-      Word{ category: "sports", text: "goal post" },
-      Word{ category: "sports", text: "golf" },
-      Word{ category: "sports", text: "half pipe" },
-      Word{ category: "sports", text: "handball" },
-      Word{ category: "sports", text: "homerun" },
-      Word{ category: "sports", text: "iceskate" },
-      Word{ category: "sports", text: "javelin" },
-      Word{ category: "sports", text: "jumprope" },
-      Word{ category: "sports", text: "lacrosse" },
-      Word{ category: "sports", text: "longjump" },
-      Word{ category: "sports", text: "minigolf" },
-      Word{ category: "sports", text: "netball" },
-      Word{ category: "sports", text: "olympics" },
-      Word{ category: "sports", text: "pingpong" },
-      Word{ category: "sports", text: "rebound" },
-      Word{ category: "sports", text: "referee" },
-      Word{ category: "sports", text: "rock climbing" },
-      Word{ category: "sports", text: "slamdunk" },
-      Word{ category: "sports", text: "sledding" },
-      Word{ category: "sports", text: "soccer" },
-      Word{ category: "sports", text: "surfing" },
-      Word{ category: "sports", text: "swimming" },
-      Word{ category: "sports", text: "tennis" },
-      Word{ category: "sports", text: "volleyball" },
-      Word{ category: "toiletries", text: "hairdye" },
-      Word{ category: "toiletries", text: "hairgel" },
-      Word{ category: "toiletries", text: "lipstick" },
-      Word{ category: "toiletries", text: "mascara" },
-      Word{ category: "toiletries", text: "nailfile" },
-      Word{ category: "toiletries", text: "perfume" },
-      Word{ category: "toiletries", text: "shampoo" },
-      Word{ category: "toiletries", text: "sunblock" },
-      Word{ category: "tool", text: "chainsaw" },
-      Word{ category: "tool", text: "compass" },
-      Word{ category: "tool", text: "crowbar" },
-      Word{ category: "tool", text: "dustpan" },
-      Word{ category: "tool", text: "scissors" },
-      Word{ category: "tool", text: "stapler" },
-      Word{ category: "tool", text: "tweezers" },
-      Word{ category: "transportation", text: "airplane" },
-      Word{ category: "transportation", text: "bicycle" },
-      Word{ category: "transportation", text: "boat" },
-      Word{ category: "transportation", text: "bus" },
-      Word{ category: "transportation", text: "dog sled" },
-      Word{ category: "transportation", text: "double decker bus" },
-      Word{ category: "transportation", text: "f-16" },
-      Word{ category: "transportation", text: "forklift" },
-      Word{ category: "transportation", text: "formula one" },
-      Word{ category: "transportation", text: "golf cart" },
-      Word{ category: "transportation", text: "helicopter" },
-      Word{ category: "transportation", text: "hoverboard" },
-      Word{ category: "transportation", text: "ice cream truck" },
-      Word{ category: "transportation", text: "jeep" },
-      Word{ category: "transportation", text: "minivan" },
-      Word{ category: "transportation", text: "ocean liner" },
-      Word{ category: "transportation", text: "rickshaw" },
-      Word{ category: "transportation", text: "rocketship" },
-      Word{ category: "transportation", text: "rowboat" },
-      Word{ category: "transportation", text: "sail soat" },
-      Word{ category: "transportation", text: "scooter" },
-      Word{ category: "transportation", text: "shuttle" },
-      Word{ category: "transportation", text: "spaceship" },
-      Word{ category: "transportation", text: "sports car" },
-      Word{ category: "transportation", text: "stagecoach" },
-      Word{ category: "transportation", text: "steam boat" },
-      Word{ category: "transportation", text: "submarine" },
-      Word{ category: "transportation", text: "tow truck" },
-      Word{ category: "transportation", text: "tractor" },
-      Word{ category: "transportation", text: "train" },
-      Word{ category: "transportation", text: "tram" },
-      Word{ category: "transportation", text: "tricycle" },
-      Word{ category: "transportation", text: "tugboat" },
-      Word{ category: "transportation", text: "unicycle" },
-      Word{ category: "transportation", text: "yacht" },
-      Word{ category: "transportation", text: "zeppelin" },
-      Word{ category: "valuables", text: "bracelet" },
-      Word{ category: "valuables", text: "crystal" },
-      Word{ category: "valuables", text: "diamond" },
-      Word{ category: "valuables", text: "emerald" },
-      Word{ category: "valuables", text: "gold bar" },
-      Word{ category: "valuables", text: "jewelry" },
-      Word{ category: "valuables", text: "pearls" },
-      Word{ category: "weapon", text: "battle axe" },
-      Word{ category: "weapon", text: "bayonet" },
-      Word{ category: "weapon", text: "bazooka" },
-      Word{ category: "weapon", text: "cannon" },
-      Word{ category: "weapon", text: "catapult" },
-      Word{ category: "weapon", text: "crossbow" },
-      Word{ category: "weapon", text: "dynamite" },
-      Word{ category: "weapon", text: "handgun" },
-      Word{ category: "weapon", text: "missile" },
-      Word{ category: "weapon", text: "shotgun" },
-      Word{ category: "weapon", text: "sword" },
-      Word{ category: "weapon", text: "torpedo" },
-      Word{ category: "weapon", text: "warhammer" }
+      word("accessory", "bandana", Easy),
+      word("accessory", "earring", Easy),
+      word("accessory", "eyepatch", Easy),
+      word("accessory", "hairtie", Easy),
+      word("accessory", "handbag", Easy),
+      word("accessory", "handkerchief", Easy),
+      word("accessory", "headband", Easy),
+      word("accessory", "kneepads", Easy),
+      word("accessory", "necklace", Easy),
+      word("accessory", "nosering", Easy),
+      word("accessory", "partyhat", Easy),
+      word("accessory", "shoelace", Easy),
+      word("accessory", "umbrella", Easy),
+      word("anatomy", "backbone", Medium),
+      word("anatomy", "eyeball", Medium),
+      word("anatomy", "eyebrow", Medium),
+      word("anatomy", "eyelash", Medium),
+      word("anatomy", "forehead", Medium),
+      word("anatomy", "kneecap", Medium),
+      word("anatomy", "knuckle", Medium),
+      word("anatomy", "mustache", Medium),
+      word("anatomy", "nosehair", Medium),
+      word("anatomy", "shoulder", Medium),
+      word("anatomy", "skeleton", Medium),
+      word("anatomy", "stomach", Medium),
+      word("anatomy", "tentacle", Medium),
+      word("anatomy", "unibrow", Medium),
+      word("anatomy", "whiskers", Medium),
+      word("animal", "aardvark", Medium),
+      word("animal", "alpaca", Medium),
+      word("animal", "ant", Easy),
+      word("animal", "anteater", Medium),
+      word("animal", "armadillo", Medium),
+      word("animal", "baboon", Medium),
+      word("animal", "badger", Medium),
+      word("animal", "bat", Easy),
+      word("animal", "bear", Easy),
+      word("animal", "beaver", Easy),
+      word("animal", "bee", Easy),
+      word("animal", "bird", Easy),
+      word("animal", "buffalo", Easy),
+      word("animal", "bull", Easy),
+      word("animal", "calf", Easy),
+      word("animal", "camel", Easy),
+      word("animal", "cat", Easy),
+      word("animal", "caterpillar", Easy),
+      word("animal", "catfish", Easy),
+      word("animal", "chameleon", Medium),
+      word("animal", "cheetah", Easy),
+      word("animal", "chicken", Easy),
+      word("animal", "chihuahua ", Easy),
+      word("animal", "chimpanzee", Easy),
+      word("animal", "cicada ", Medium),
+      word("animal", "cockroach", Easy),
+      word("animal", "cow", Easy),
+      word("animal", "crab", Easy),
+      word("animal", "crane", Medium),
+      word("animal", "cricket", Easy),
+      word("animal", "crocodile", Easy),
+      word("animal", "deer", Easy),
+      word("animal", "dinosaur", Easy),
+      word("animal", "dog", Easy),
+      word("animal", "dolphin", Easy),
+      word("animal", "donkey", Easy),
+      word("animal", "dragonfly", Easy),
+      word("animal", "duck", Easy),
+      word("animal", "eagle", Easy),
+      word("animal", "elephant", Easy),
+      word("animal", "firefly", Easy),
+      word("animal", "fish", Easy),
+      word("animal", "flamingo", Easy),
+      word("animal", "fly", Easy),
+      word("animal", "fox", Easy),
+      word("animal", "frog", Easy),
+      word("animal", "giraffe", Easy),
+      word("animal", "goat", Easy),
+      word("animal", "goldfish", Easy),
+      word("animal", "goose", Easy),
+      word("animal", "gorilla", Easy),
+      word("animal", "grasshopper", Easy),
+      word("animal", "hamster", Easy),
+      word("animal", "hippopotamus", Easy),
+      word("animal", "horse", Easy),
+      word("animal", "hyena", Medium),
+      word("animal", "jaguar", Medium),
+      word("animal", "kangaroo", Easy),
+      word("animal", "kitten", Easy),
+      word("animal", "ladybug", Easy),
+      word("animal", "leopard", Easy),
+      word("animal", "lion", Easy),
+      word("animal", "lizard", Easy),
+      word("animal", "llama", Easy),
+      word("animal", "lobster", Medium),
+      word("animal", "mammoth", Medium),
+      word("animal", "monkey", Easy),
+      word("animal", "moose", Medium),
+      word("animal", "mosquito", Easy),
+      word("animal", "mouse", Easy),
+      word("animal", "narwhal", Medium),
+      word("animal", "octopus", Easy),
+      word("animal", "ostrich", Medium),
+      word("animal", "otter", Easy),
+      word("animal", "owl", Easy),
+      word("animal", "oyster", Medium),
+      word("animal", "panda", Easy),
+      word("animal", "pangolin", Hard),
+      word("animal", "panther", Easy),
+      word("animal", "parrot", Easy),
+      word("animal", "peacock", Easy),
+      word("animal", "pelican", Easy),
+      word("animal", "penguin", Easy),
+      word("animal", "pig", Easy),
+      word("animal", "pigeon", Easy),
+      word("animal", "pitbull", Easy),
+      word("animal", "platypus", Hard),
+      word("animal", "poodle", Easy),
+      word("animal", "porcupine", Easy),
+      word("animal", "puppy", Easy),
+      word("animal", "rabbit", Easy),
+      word("animal", "raccoon", Easy),
+      word("animal", "rat", Easy),
+      word("animal", "reindeer", Easy),
+      word("animal", "reptile", Medium),
+      word("animal", "rhinoceros", Easy),
+      word("animal", "rooster", Easy),
+      word("animal", "scorpion", Easy),
+      word("animal", "seagull", Easy),
+      word("animal", "seahorse", Easy),
+      word("animal", "seal", Easy),
+      word("animal", "shark", Easy),
+      word("animal", "sheep", Easy),
+      word("animal", "shrimp", Easy),
+      word("animal", "skunk", Easy),
+      word("animal", "snail", Easy),
+      word("animal", "snake", Easy),
+      word("animal", "spider", Easy),
+      word("animal", "squid", Easy),
+      word("animal", "squirrel", Easy),
+      word("animal", "starfish", Easy),
+      word("animal", "stork", Medium),
+      word("animal", "swan", Easy),
+      word("animal", "tadpole", Medium),
+      word("animal", "tiger", Easy),
+      word("animal", "toad", Easy),
+      word("animal", "tortoise", Easy),
+      word("animal", "tropical fish", Easy),
+      word("animal", "tuna", Medium),
+      word("animal", "turkey", Easy),
+      word("animal", "turtle", Easy),
+      word("animal", "vulture", Medium),
+      word("animal", "walrus", Easy),
+      word("animal", "whale", Easy),
+      word("animal", "wolf", Easy),
+      word("animal", "wombat", Medium),
+      word("animal", "worm", Easy),
+      word("animal", "zebra", Easy),
+      word("clothing", "bandana", Easy),
+      word("clothing", "belt", Easy),
+      word("clothing", "bikini", Easy),
+      word("clothing", "boots", Easy),
+      word("clothing", "bowtie", Easy),
+      word("clothing", "cap", Easy),
+      word("clothing", "chasity belt", Easy),
+      word("clothing", "coat", Easy),
+      word("clothing", "cowboy hat", Easy),
+      word("clothing", "dress", Easy),
+      word("clothing", "earmuffs", Easy),
+      word("clothing", "eyepatch", Easy),
+      word("clothing", "glasses", Easy),
+      word("clothing", "gloves", Easy),
+      word("clothing", "hairtie", Easy),
+      word("clothing", "hat", Easy),
+      word("clothing", "headband", Easy),
+      word("clothing", "hoodie", Easy),
+      word("clothing", "jacket", Easy),
+      word("clothing", "jeans", Easy),
+      word("clothing", "kneepads", Easy),
+      word("clothing", "mittens", Easy),
+      word("clothing", "necklace", Easy),
+      word("clothing", "overalls", Easy),
+      word("clothing", "pajamas", Easy),
+      word("clothing", "pantsuit", Easy),
+      word("clothing", "partyhat", Easy),
+      word("clothing", "raincoat", Easy),
+      word("clothing", "sandals", Easy),
+      word("clothing", "scarf", Easy),
+      word("clothing", "shirt", Easy),
+      word("clothing", "shoes", Easy),
+      word("clothing", "skirt", Easy),
+      word("clothing", "slippers", Easy),
+      word("clothing", "sneakers", Easy),
+      word("clothing", "socks", Easy),
+      word("clothing", "sombrero", Easy),
+      word("clothing", "stockings", Easy),
+      word("clothing", "suit", Easy),
+      word("clothing", "sweater", Easy),
+      word("clothing", "sweatshirt", Easy),
+      word("clothing", "swimsuit", Easy),
+      word("clothing", "t-shirt", Easy),
+      word("clothing", "tie", Easy),
+      word("clothing", "tophat", Easy),
+      word("clothing", "trousers", Easy),
+      word("clothing", "underpants", Easy),
+      word("clothing", "tophat", Easy),
+      word("computing", "computer", Hard),
+      word("computing", "facebook", Hard),
+      word("computing", "firewall", Hard),
+      word("computing", "hashtag", Hard),
+      word("computing", "keyboard", Hard),
+      word("computing", "macbook", Hard),
+      word("computing", "password", Hard),
+      word("computing", "twitter", Hard),
+      word("fairytale", "aladdin", Medium),
+      word("fairytale", "ali baba and the forty thieves", Medium),
+      word("fairytale", "beauty and the beast", Medium),
+      word("fairytale", "cinderella", Medium),
+      word("fairytale", "goldilocks and the three bears", Medium),
+      word("fairytale", "hansel and gretel", Medium),
+      word("fairytale", "puss in boots", Medium),
+      word("fairytale", "rapunzel", Medium),
+      word("fairytale", "rumpelstiltskin", Medium),
+      word("fairytale", "sinbad", Medium),
+      word("fairytale", "sleeping beauty", Medium),
+      word("fairytale", "snow white", Medium),
+      word("fairytale", "the emperor's new clothes", Medium),
+      word("fairytale", "the fisherman and the jinni", Medium),
+      word("fairytale", "the frog prince", Medium),
+      word("fairytale", "the golden fish", Medium),
+      word("fairytale", "the golden goose", Medium),
+      word("fairytale", "the little mermaid", Medium),
+      word("fairytale", "the princess and the pea", Medium),
+      word("fairytale", "the steadfast tin soldier", Medium),
+      word("fairytale", "the three little pigs", Medium),
+      word("fairytale", "the tin soldier", Medium),
+      word("fairytale", "the tinderbox", Medium),
+      word("fairytale", "the ugly duckling", Medium),
+      word("fairytale", "thumbelina", Medium),
+      word("fairytale", "thumbelina", Medium),
+      word("fairytale", "thumbelina", Medium),
+      word("family", "aunt", Easy),
+      word("family", "brother", Easy),
+      word("family", "children", Easy),
+      word("family", "cousin", Easy),
+      word("family", "father", Easy),
+      word("family", "father in law", Easy),
+      word("family", "god father", Easy),
+      word("family", "god mother", Easy),
+      word("family", "granddaugther", Easy),
+      word("family", "grandma", Easy),
+      word("family", "grandpa", Easy),
+      word("family", "grandson", Easy),
+      word("family", "husband", Easy),
+      word("family", "mother", Easy),
+      word("family", "mother in law", Easy),
+      word("family", "nephew", Easy),
+      word("family", "niece", Easy),
+      word("family", "parents", Easy),
+      word("family", "sister", Easy),
+      word("family", "step brother", Easy),
+      word("family", "step father", Easy),
+      word("family", "step mother", Easy),
+      word("family", "step sister", Easy),
+      word("family", "uncle", Easy),
+      word("family", "wife", Easy),
+      word("famous landmark", "acropolis", Medium),
+      word("famous landmark", "big ben", Medium),
+      word("famous landmark", "brandenburg gate", Medium),
+      word("famous landmark", "capitol hill", Medium),
+      word("famous landmark", "christ the redeemer statue", Medium),
+      word("famous landmark", "colosseum", Medium),
+      word("famous landmark", "easter island", Medium),
+      word("famous landmark", "empire state building", Medium),
+      word("famous landmark", "fontana de trevi", Medium),
+      word("famous landmark", "golden gate bridge ", Medium),
+      word("famous landmark", "grand canyon", Medium),
+      word("famous landmark", "leaning tower of pisa", Medium),
+      word("famous landmark", "loch ness", Medium),
+      word("famous landmark", "mount rushmore", Medium),
+      word("famous landmark", "niagra falls", Medium),
+      word("famous landmark", "statue of liberty", Medium),
+      word("famous landmark", "stonehenge", Medium),
+      word("famous landmark", "sydney opera house", Medium),
+      word("famous landmark", "the eiffel tower", Medium),
+      word("famous landmark", "the forbidden city", Medium),
+      word("famous landmark", "the great chinese wall", Medium),
+      word("famous landmark", "the hollywood sign ", Medium),
+      word("famous landmark", "the kremlin", Medium),
+      word("famous landmark", "the little mermaid", Medium),
+      word("famous landmark", "the louvre ", Medium),
+      word("famous landmark", "the pyramids", Medium),
+      word("famous landmark", "the sphinx", Medium),
+      word("famous landmark", "the taj mahal", Medium),
+      word("famous person", "angelina jolie", Medium),
+      word("famous person", "beckham", Medium),
+      word("famous person", "bob dylan", Medium),
+      word("famous person", "brad pitt", Medium),
+      word("famous person", "bruce lee", Medium),
+      word("famous person", "colbert", Hard),
+      word("famous person", "donald trump", Easy),
+      word("famous person", "eminem", Medium),
+      word("famous person", "gandhi", Easy),
+      word("famous person", "geronimo", Hard),
+      word("famous person", "greenday", Hard),
+      word("famous person", "jay leno", Hard),
+      word("famous person", "justin bieber", Easy),
+      word("famous person", "kate moss", Hard),
+      word("famous person", "lady gaga", Easy),
+      word("famous person", "lil wayne", Hard),
+      word("famous person", "lincoln", Easy),
+      word("famous person", "ludacris", Hard),
+      word("famous person", "madonna", Easy),
+      word("famous person", "mc hammer", Hard),
+      word("famous person", "picasso", Easy),
+      word("famous person", "pitbull", Medium),
+      word("famous person", "rihanna", Medium),
+      word("famous person", "tom hanks", Medium),
+      word("famous person", "van gogh", Easy),
+      word("famous person", "vincent van gogh", Easy),
+      word("festivity", "bar mitzvah", Medium),
+      word("festivity", "birthday", Medium),
+      word("festivity", "black friday", Medium),
+      word("festivity", "christmas", Medium),
+      word("festivity", "columbus day", Medium),
+      word("festivity", "divali", Medium),
+      word("festivity", "día de muertos", Medium),
+      word("festivity", "easter", Medium),
+      word("festivity", "election", Medium),
+      word("festivity", "festivus", Medium),
+      word("festivity", "graduation", Medium),
+      word("festivity", "groundhog day", Medium),
+      word("festivity", "halloween", Medium),
+      word("festivity", "hanukkah", Medium),
+      word("festivity", "independence day", Medium),
+      word("festivity", "kwanzaa", Medium),
+      word("festivity", "mother`s day", Medium),
+      word("festivity", "new year`s eve", Medium),
+      word("festivity", "octoberfest", Medium),
+      word("festivity", "passover", Medium),
+      word("festivity", "ramadan", Medium),
+      word("festivity", "st. patrick`s day", Medium),
+      word("festivity", "thanks giving", Medium),
+      word("festivity", "valentine`s day", Medium),
+      word("festivity", "wedding", Medium),
+      word("film genres", "action", Hard),
+      word("film genres", "animation", Hard),
+      word("film genres", "comedy", Hard),
+      word("film genres", "disasters", Hard),
+      word("film genres", "documentry", Hard),
+      word("film genres", "gangster", Hard),
+      word("film genres", "horror", Hard),
+      word("film genres", "mockumentry", Hard),
+      word("film genres", "musical", Hard),
+      word("film genres", "rom-com", Hard),
+      word("film genres", "sci-fi", Hard),
+      word("film genres", "slapstick", Hard),
+      word("film genres", "sport", Hard),
+      word("film genres", "thriller", Hard),
+      word("film genres", "war", Hard),
+      word("film genres", "western", Hard),
+      word("film genres", "zombies", Hard),
+      word("food", "bacon", Easy),
+      word("food", "baguette", Easy),
+      word("food", "barbeque", Easy),
+      word("food", "beans", Easy),
+      word("food", "beer", Easy),
+      word("food", "burrito", Easy),
+      word("food", "cake", Easy),
+      word("food", "cereal", Easy),
+      word("food", "champagne", Easy),
+      word("food", "cheese", Easy),
+      word("food", "chicken wings", Easy),
+      word("food", "coffee", Easy),
+      word("food", "corndog", Easy),
+      word("food", "croissant", Easy),
+      word("food", "cupcake", Easy),
+      word("food", "eggroll", Easy),
+      word("food", "espresso", Easy),
+      word("food", "fast food", Easy),
+      word("food", "fish and chips", Easy),
+      word("food", "frosting", Easy),
+      word("food", "hambone", Easy),
+      word("food", "hamburger", Easy),
+      word("food", "hamwich", Easy),
+      word("food", "hot sauce", Easy),
+      word("food", "junk food", Easy),
+      word("food", "kebab", Easy),
+      word("food", "ketchup", Easy),
+      word("food", "lasagna", Easy),
+      word("food", "lemonade", Easy),
+      word("food", "macaroni", Easy),
+      word("food", "martini", Easy),
+      word("food", "mashed potatoes", Easy),
+      word("food", "meatballs", Easy),
+      word("food", "milk", Easy),
+      word("food", "mushroom", Easy),
+      word("food", "mustard", Easy),
+      word("food", "noodles", Easy),
+      word("food", "oatmeal", Easy),
+      word("food", "pancake", Easy),
+      word("food", "pasta", Easy),
+      word("food", "pie", Easy),
+      word("food", "pizza", Easy),
+      word("food", "popcorn", Easy),
+      word("food", "pretzel", Easy),
+      word("food", "pumpkin", Easy),
+      word("food", "salad", Easy),
+      word("food", "sandwich", Easy),
+      word("food", "sausage", Easy),
+      word("food", "seafood", Easy),
+      word("food", "smoothie", Easy),
+      word("food", "snowcone", Easy),
+      word("food", "soy sauce", Easy),
+      word("food", "sushi", Easy),
+      word("food", "tea", Easy),
+      word("food", "tequila", Easy),
+      word("food", "waffles", Easy),
+      word("food", "whiskey", Easy),
+      word("furniture", "barstool", Easy),
+      word("furniture", "bathtub", Easy),
+      word("furniture", "bed", Easy),
+      word("furniture", "bookcase", Easy),
+      word("furniture", "bunkbed", Easy),
+      word("furniture", "cabinet", Easy),
+      word("furniture", "chair", Easy),
+      word("furniture", "dresser", Easy),
+      word("furniture", "waterbed", Easy),
+      word("games", "balloon", Medium),
+      word("games", "checkers", Medium),
+      word("games", "dreidel", Medium),
+      word("games", "gameboy", Medium),
+      word("games", "hulahoop", Medium),
+      word("games", "joystick", Medium),
+      word("games", "monopoly", Medium),
+      word("games", "pinball", Medium),
+      word("games", "pinwheel", Medium),
+      word("games", "scrabble", Medium),
+      word("games", "swingset", Medium),
+      word("games", "yahtzee", Medium),
+      word("geography", "america", Medium),
+      word("geography", "atlanta", Medium),
+      word("geography", "bahamas", Medium),
+      word("geography", "england", Medium),
+      word("geography", "finland", Medium),
+      word("geography", "florida", Medium),
+      word("geography", "germany", Medium),
+      word("geography", "holland", Medium),
+      word("geography", "hongkong", Medium),
+      word("geography", "ireland", Medium),
+      word("geography", "jamaica", Medium),
+      word("geography", "new-york", Medium),
+      word("geography", "orlando", Medium),
+      word("geography", "scotland", Medium),
+      word("location", "airport", Medium),
+      word("location", "apartment building", Medium),
+      word("location", "bank", Medium),
+      word("location", "bar", Medium),
+      word("location", "barber shop", Medium),
+      word("location", "barn", Medium),
+      word("location", "beach", Medium),
+      word("location", "book store", Medium),
+      word("location", "bowling alley", Medium),
+      word("location", "bus stop", Medium),
+      word("location", "casino", Medium),
+      word("location", "castle", Medium),
+      word("location", "cathedral", Medium),
+      word("location", "cemetery", Medium),
+      word("location", "church", Medium),
+      word("location", "circus", Medium),
+      word("location", "convenience store", Medium),
+      word("location", "courthouse", Medium),
+      word("location", "department store", Medium),
+      word("location", "desert", Medium),
+      word("location", "disco", Medium),
+      word("location", "dungeon", Medium),
+      word("location", "fire station", Medium),
+      word("location", "garden", Medium),
+      word("location", "gas station", Medium),
+      word("location", "golf course", Medium),
+      word("location", "hospital", Medium),
+      word("location", "house", Medium),
+      word("location", "library", Medium),
+      word("location", "lighthouse", Medium),
+      word("location", "mall", Medium),
+      word("location", "mansion", Medium),
+      word("location", "movie theater", Medium),
+      word("location", "museum", Medium),
+      word("location", "office building", Medium),
+      word("location", "opera house", Medium),
+      word("location", "orchard", Medium),
+      word("location", "palace", Medium),
+      word("location", "petshop", Medium),
+      word("location", "police station", Medium),
+      word("location", "post office", Medium),
+      word("location", "prison", Medium),
+      word("location", "pub", Medium),
+      word("location", "restaurant", Medium),
+      word("location", "safari", Medium),
+      word("location", "saw mill", Medium),
+      word("location", "school", Medium),
+      word("location", "skyscraper", Medium),
+      word("location", "supermarket", Medium),
+      word("location", "swimming pool", Medium),
+      word("location", "temple", Medium),
+      word("location", "theater", Medium),
+      word("location", "theme park", Medium),
+      word("location", "train station", Medium),
+      word("location", "vet clinic", Medium),
+      word("location", "zoo", Medium),
+      word("medical", "crutches", Medium),
+      word("medical", "dandruff", Medium),
+      word("medical", "dentures", Medium),
+      word("medical", "firstaid", Medium),
+      word("medical", "headache", Medium),
+      word("medical", "hospital", Medium),
+      word("medical", "insomnia", Medium),
+      word("medical", "medicine", Medium),
+      word("medical", "papercut", Medium),
+      word("medical", "seasick", Medium),
+      word("medical", "sunburn", Medium),
+      word("medical", "vaccine", Medium),
+      word("medical", "vitamin", Medium),
+      word("movies", "a clockwork orange", Hard),
+      word("movies", "alien", Easy),
+      word("movies", "armagedon", Hard),
+      word("movies", "austin powers", Hard),
+      word("movies", "avengers", Medium),
+      word("movies", "back to the future", Medium),
+      word("movies", "beetlejuice", Hard),
+      word("movies", "braveheart", Hard),
+      word("movies", "die hard", Medium),
+      word("movies", "e.t.", Easy),
+      word("movies", "fargo", Hard),
+      word("movies", "finding nemo", Easy),
+      word("movies", "forrest gump", Hard),
+      word("movies", "frozen", Easy),
+      word("movies", "gladiator", Medium),
+      word("movies", "golden eye", Hard),
+      word("movies", "gone with the wind", Hard),
+      word("movies", "groundhog day", Hard),
+      word("movies", "home alone", Easy),
+      word("movies", "inception", Hard),
+      word("movies", "indiana jones", Medium),
+      word("movies", "inglourious basterds", Hard),
+      word("movies", "jaws", Easy),
+      word("movies", "jurassic park", Easy),
+      word("movies", "king kong", Easy),
+      word("movies", "lion king", Easy),
+      word("movies", "mary poppins", Medium),
+      word("movies", "minority report", Hard),
+      word("movies", "mission impossible", Medium),
+      word("movies", "monty python and the holy grail", Hard),
+      word("movies", "princess bride", Hard),
+      word("movies", "pulp fiction", Hard),
+      word("movies", "robocop", Medium),
+      word("movies", "rocky", Medium),
+      word("movies", "rocky horror picture show", Hard),
+      word("movies", "saving private ryan", Hard),
+      word("movies", "scarface", Hard),
+      word("movies", "shrek", Easy),
+      word("movies", "star wars", Easy),
+      word("movies", "terminator", Medium),
+      word("movies", "the good, the bad and the ugly", Hard),
+      word("movies", "the green mile", Hard),
+      word("movies", "the lord of the rings", Medium),
+      word("movies", "the matrix", Medium),
+      word("movies", "the shawshank redemption", Hard),
+      word("movies", "the social network", Hard),
+      word("movies", "the wizard of oz", Medium),
+      word("movies", "titanic", Easy),
+      word("movies", "toy story", Easy),
+      word("movies", "west side story", Hard),
+      word("movies", "wonder woman", Medium),
+      word("movies", "x-men", Medium),
+      word("music band", "ac/dc", Medium),
+      word("music band", "adele", Easy),
+      word("music band", "beatles", Easy),
+      word("music band", "beyonce", Easy),
+      word("music band", "billie eilish", Medium),
+      word("music band", "bon jovi", Medium),
+      word("music band", "bruno mars", Medium),
+      word("music band", "childish gambino", Hard),
+      word("music band", "coldplay", Medium),
+      word("music band", "daftpunk", Hard),
+      word("music band", "david bowie", Medium),
+      word("music band", "dr. dre", Hard),
+      word("music band", "drake", Medium),
+      word("music band", "ed shiran", Medium),
+      word("music band", "elton john", Easy),
+      word("music band", "eminem", Medium),
+      word("music band", "guns and roses", Hard),
+      word("music band", "jay z", Hard),
+      word("music band", "justin bieber", Easy),
+      word("music band", "justin timberlake", Medium),
+      word("music band", "kanye west", Medium),
+      word("music band", "katy perry", Easy),
+      word("music band", "lady gaga", Easy),
+      word("music band", "led zeppelin", Hard),
+      word("music band", "mariah carey", Medium),
+      word("music band", "michael jackson", Easy),
+      word("music band", "muse", Hard),
+      word("music band", "nicki minaj", Medium),
+      word("music band", "nirvana", Medium),
+      word("music band", "pink floyd", Hard),
+      word("music band", "pitbull", Hard),
+      word("music band", "prince", Hard),
+      word("music band", "queen", Easy),
+      word("music band", "rihana", Medium),
+      word("music band", "skrillex", Hard),
+      word("music band", "snoop dogg", Medium),
+      word("music band", "taylor swift", Easy),
+      word("music band", "the black eyed peas", Hard),
+      word("music band", "tupac shakur", Hard),
+      word("music band", "u2", Medium),
+      word("music genre", "blues", Hard),
+      word("music genre", "classical", Hard),
+      word("music genre", "country", Hard),
+      word("music genre", "disco", Hard),
+      word("music genre", "dubstep", Hard),
+      word("music genre", "elevator music", Hard),
+      word("music genre", "grunge", Hard),
+      word("music genre", "heavy metal", Hard),
+      word("music genre", "hip hop", Hard),
+      word("music genre", "house", Hard),
+      word("music genre", "j-pop", Hard),
+      word("music genre", "jazz", Hard),
+      word("music genre", "pop", Hard),
+      word("music genre", "punk", Hard),
+      word("music genre", "r&b", Hard),
+      word("music genre", "rap", Hard),
+      word("music genre", "reggae", Hard),
+      word("music genre", "rock", Hard),
+      word("music genre", "swing", Hard),
+      word("music genre", "techno", Hard),
+      word("musical instrument", "accordion", Medium),
+      word("musical instrument", "bagpipes", Medium),
+      word("musical instrument", "banjo", Medium),
+      word("musical instrument", "bass guitar", Medium),
+      word("musical instrument", "bugle", Medium),
+      word("musical instrument", "castanets", Medium),
+      word("musical instrument", "cello", Medium),
+      word("musical instrument", "clarinet", Medium),
+      word("musical instrument", "cowbell", Medium),
+      word("musical instrument", "cymbal", Medium),
+      word("musical instrument", "didgeridoo", Medium),
+      word("musical instrument", "drums", Medium),
+      word("musical instrument", "electric guitar", Medium),
+      word("musical instrument", "fiddle", Medium),
+      word("musical instrument", "flute", Medium),
+      word("musical instrument", "gong", Medium),
+      word("musical instrument", "harmonica", Medium),
+      word("musical instrument", "harp", Medium),
+      word("musical instrument", "horn", Medium),
+      word("musical instrument", "jukebox", Medium),
+      word("musical instrument", "kazoo", Medium),
+      word("musical instrument", "lute", Medium),
+      word("musical instrument", "maraca", Medium),
+      word("musical instrument", "marimba", Medium),
+      word("musical instrument", "oboe", Medium),
+      word("musical instrument", "ocarina", Medium),
+      word("musical instrument", "piano", Medium),
+      word("musical instrument", "piccolo", Medium),
+      word("musical instrument", "saxophone", Medium),
+      word("musical instrument", "sitar", Medium),
+      word("musical instrument", "tambourine", Medium),
+      word("musical instrument", "triangle", Medium),
+      word("musical instrument", "trombone", Medium),
+      word("musical instrument", "trumpet", Medium),
+      word("musical instrument", "tuba", Medium),
+      word("musical instrument", "ukulele", Medium),
+      word("musical instrument", "violin", Medium),
+      word("musical instrument", "washboard", Medium),
+      word("musical instrument", "whistle", Medium),
+      word("musical instrument", "xylophone", Medium),
+      word("mythology", "amazon warrior", Hard),
+      word("mythology", "basilisk", Hard),
+      word("mythology", "bigfoot", Hard),
+      word("mythology", "centaur", Hard),
+      word("mythology", "chimera", Hard),
+      word("mythology", "cyclop", Hard),
+      word("mythology", "dragon", Hard),
+      word("mythology", "dryad", Hard),
+      word("mythology", "elf", Hard),
+      word("mythology", "fairy", Hard),
+      word("mythology", "genie", Hard),
+      word("mythology", "ghost", Hard),
+      word("mythology", "ghoul", Hard),
+      word("mythology", "giant", Hard),
+      word("mythology", "goblin", Hard),
+      word("mythology", "golem", Hard),
+      word("mythology", "griffin", Hard),
+      word("mythology", "imp", Hard),
+      word("mythology", "lizard people", Hard),
+      word("mythology", "magic carpet", Hard),
+      word("mythology", "manticore", Hard),
+      word("mythology", "medusa", Hard),
+      word("mythology", "mermaid", Hard),
+      word("mythology", "minotaur", Hard),
+      word("mythology", "minotaur", Hard),
+      word("mythology", "mummy", Hard),
+      word("mythology", "nymph", Hard),
+      word("mythology", "ogre", Hard),
+      word("mythology", "pegasus", Hard),
+      word("mythology", "phoenix", Hard),
+      word("mythology", "satyr", Hard),
+      word("mythology", "sphinx", Hard),
+      word("mythology", "troll", Hard),
+      word("mythology", "unicorn", Hard),
+      word("mythology", "vampire", Hard),
+      word("mythology", "werewolf", Hard),
+      word("mythology", "zombie", Hard),
+      word("plant", "avocado", Medium),
+      word("plant", "bamboo", Medium),
+      word("plant", "banana", Medium),
+      word("plant", "broccoli", Medium),
+      word("plant", "cabbage", Medium),
+      word("plant", "cactus", Medium),
+      word("plant", "carrot", Medium),
+      word("plant", "celery", Medium),
+      word("plant", "cherry", Medium),
+      word("plant", "cilantro", Medium),
+      word("plant", "coconut", Medium),
+      word("plant", "cucumber", Medium),
+      word("plant", "eggplant", Medium),
+      word("plant", "garlic", Medium),
+      word("plant", "grapes", Medium),
+      word("plant", "lemon", Medium),
+      word("plant", "lime", Medium),
+      word("plant", "mango", Medium),
+      word("plant", "oak", Medium),
+      word("plant", "orange", Medium),
+      word("plant", "palmtree", Medium),
+      word("plant", "peaches", Medium),
+      word("plant", "persimmon", Medium),
+      word("plant", "pineapple", Medium),
+      word("plant", "pinecone", Medium),
+      word("plant", "pinetree", Medium),
+      word("plant", "potato", Medium),
+      word("plant", "primrose", Medium),
+      word("plant", "seaweed", Medium),
+      word("plant", "spinach", Medium),
+      word("plant", "tomato", Medium),
+      word("plant", "watermelon", Medium),
+      word("plant", "wheat", Medium),
+      word("plant", "zucchini", Medium),
+      word("pop culture", "aladdin", Easy),
+      word("pop culture", "aquaman", Medium),
+      word("pop culture", "asterix", Medium),
+      word("pop culture", "autobots", Medium),
+      word("pop culture", "avatar", Medium),
+      word("pop culture", "barbie", Easy),
+      word("pop culture", "batman", Easy),
+      word("pop culture", "beavis", Hard),
+      word("pop culture", "bowser", Medium),
+      word("pop culture", "catwoman", Medium),
+      word("pop culture", "conan the barbarian", Hard),
+      word("pop culture", "garfield", Easy),
+      word("pop culture", "hawkeye", Medium),
+      word("pop culture", "iron chef", Hard),
+      word("pop culture", "kermit", Easy),
+      word("pop culture", "legend of zelda", Medium),
+      word("pop culture", "metroid", Hard),
+      word("pop culture", "mickey mouse", Easy),
+      word("pop culture", "pikachu", Easy),
+      word("pop culture", "rick & morty", Medium),
+      word("pop culture", "riddler", Hard),
+      word("pop culture", "rugrats", Hard),
+      word("pop culture", "scooby doo", Easy),
+      word("pop culture", "simba", Easy),
+      word("pop culture", "splinter", Hard),
+      word("pop culture", "spongebob", Easy),
+      word("pop culture", "superman", Easy),
+      word("pop culture", "the simpsons", Easy),
+      word("pop culture", "twoface", Hard),
+      word("pop culture", "voldemort", Medium),
+      word("profession", "artist", Medium),
+      word("profession", "assassin", Medium),
+      word("profession", "barber", Medium),
+      word("profession", "butcher", Medium),
+      word("profession", "captain", Medium),
+      word("profession", "cashier", Medium),
+      word("profession", "chef", Medium),
+      word("profession", "clown", Medium),
+      word("profession", "cook", Medium),
+      word("profession", "cowboy", Medium),
+      word("profession", "cowgirl", Medium),
+      word("profession", "dentist", Medium),
+      word("profession", "dj", Medium),
+      word("profession", "doctor", Medium),
+      word("profession", "drummer", Medium),
+      word("profession", "farmer", Medium),
+      word("profession", "firefighter", Medium),
+      word("profession", "gangster", Medium),
+      word("profession", "gardener", Medium),
+      word("profession", "gymnast", Medium),
+      word("profession", "hacker", Medium),
+      word("profession", "janitor", Medium),
+      word("profession", "lawyer", Medium),
+      word("profession", "magician", Medium),
+      word("profession", "mailman", Medium),
+      word("profession", "milkman", Medium),
+      word("profession", "movie director", Medium),
+      word("profession", "opera singer", Medium),
+      word("profession", "painter", Medium),
+      word("profession", "pilot", Medium),
+      word("profession", "plumber", Medium),
+      word("profession", "policeman", Medium),
+      word("profession", "poolboy", Medium),
+      word("profession", "priest", Medium),
+      word("profession", "princess", Medium),
+      word("profession", "sheriff", Medium),
+      word("profession", "soldier", Medium),
+      word("profession", "surgeon", Medium),
+      word("profession", "teacher", Medium),
+      word("profession", "teacher", Medium),
+      word("profession", "waiter", Medium),
+      word("profession", "warrior", Medium),
+      word("profession", "window cleaner ", Medium),
+      word("profession", "wrestler", Medium),
+      word("snacks", "brownie", Easy),
+      word("snacks", "cookie", Easy),
+      word("snacks", "doritos", Easy),
+      word("snacks", "icecream", Easy),
+      word("snacks", "lollipop", Easy),
+      word("snacks", "popsicle", Easy),
+      word("snacks", "skittles", Easy),
+      word("snacks", "sour candy", Easy),
+      word("snacks", "sour candy", Easy),
+      word("snacks", "twinkie", Easy),
+      word("social", "bromance", Medium),
+      word("social", "divorce", Medium),
+      word("social", "follower", Medium),
+      word("social", "frenemy", Medium),
+      word("social", "highfive", Medium),
+      word("social", "marriage", Medium),
+      word("sports", "archery", Medium),
+      word("sports", "baseball", Medium),
+      word("sports", "basketball", Medium),
+      word("sports", "bowling", Medium),
+      word("sports", "boxing", Medium),
+      word("sports", "cricket", Medium),
+      word("sports", "curling", Medium),
+      word("sports", "dumbbell", Medium),
+      word("sports", "fencing", Medium),
+      word("sports", "fishing", Medium),
+      word("sports", "football", Medium),
+      word("sports", "frisbee", Medium),
+      word("sports", "goal post", Medium),
+      word("sports", "golf", Medium),
+      word("sports", "half pipe", Medium),
+      word("sports", "handball", Medium),
+      word("sports", "homerun", Medium),
+      word("sports", "iceskate", Medium),
+      word("sports", "javelin", Medium),
+      word("sports", "jumprope", Medium),
+      word("sports", "lacrosse", Medium),
+      word("sports", "longjump", Medium),
+      word("sports", "minigolf", Medium),
+      word("sports", "netball", Medium),
+      word("sports", "olympics", Medium),
+      word("sports", "pingpong", Medium),
+      word("sports", "rebound", Medium),
+      word("sports", "referee", Medium),
+      word("sports", "rock climbing", Medium),
+      word("sports", "slamdunk", Medium),
+      word("sports", "sledding", Medium),
+      word("sports", "soccer", Medium),
+      word("sports", "surfing", Medium),
+      word("sports", "swimming", Medium),
+      word("sports", "tennis", Medium),
+      word("sports", "volleyball", Medium),
+      word("toiletries", "hairdye", Easy),
+      word("toiletries", "hairgel", Easy),
+      word("toiletries", "lipstick", Easy),
+      word("toiletries", "mascara", Easy),
+      word("toiletries", "nailfile", Easy),
+      word("toiletries", "perfume", Easy),
+      word("toiletries", "shampoo", Easy),
+      word("toiletries", "sunblock", Easy),
+      word("tool", "chainsaw", Easy),
+      word("tool", "compass", Easy),
+      word("tool", "crowbar", Easy),
+      word("tool", "dustpan", Easy),
+      word("tool", "scissors", Easy),
+      word("tool", "stapler", Easy),
+      word("tool", "tweezers", Easy),
+      word("transportation", "airplane", Easy),
+      word("transportation", "bicycle", Easy),
+      word("transportation", "boat", Easy),
+      word("transportation", "bus", Easy),
+      word("transportation", "dog sled", Easy),
+      word("transportation", "double decker bus", Easy),
+      word("transportation", "f-16", Easy),
+      word("transportation", "forklift", Easy),
+      word("transportation", "formula one", Easy),
+      word("transportation", "golf cart", Easy),
+      word("transportation", "helicopter", Easy),
+      word("transportation", "hoverboard", Easy),
+      word("transportation", "ice cream truck", Easy),
+      word("transportation", "jeep", Easy),
+      word("transportation", "minivan", Easy),
+      word("transportation", "ocean liner", Easy),
+      word("transportation", "rickshaw", Easy),
+      word("transportation", "rocketship", Easy),
+      word("transportation", "rowboat", Easy),
+      word("transportation", "sail soat", Easy),
+      word("transportation", "scooter", Easy),
+      word("transportation", "shuttle", Easy),
+      word("transportation", "spaceship", Easy),
+      word("transportation", "sports car", Easy),
+      word("transportation", "stagecoach", Easy),
+      word("transportation", "steam boat", Easy),
+      word("transportation", "submarine", Easy),
+      word("transportation", "tow truck", Easy),
+      word("transportation", "tractor", Easy),
+      word("transportation", "train", Easy),
+      word("transportation", "tram", Easy),
+      word("transportation", "tricycle", Easy),
+      word("transportation", "tugboat", Easy),
+      word("transportation", "unicycle", Easy),
+      word("transportation", "yacht", Easy),
+      word("transportation", "zeppelin", Easy),
+      word("valuables", "bracelet", Easy),
+      word("valuables", "crystal", Easy),
+      word("valuables", "diamond", Easy),
+      word("valuables", "emerald", Easy),
+      word("valuables", "gold bar", Easy),
+      word("valuables", "jewelry", Easy),
+      word("valuables", "pearls", Easy),
+      word("weapon", "battle axe", Easy),
+      word("weapon", "bayonet", Easy),
+      word("weapon", "bazooka", Easy),
+      word("weapon", "cannon", Easy),
+      word("weapon", "catapult", Easy),
+      word("weapon", "crossbow", Easy),
+      word("weapon", "dynamite", Easy),
+      word("weapon", "handgun", Easy),
+      word("weapon", "missile", Easy),
+      word("weapon", "shotgun", Easy),
+      word("weapon", "sword", Easy),
+      word("weapon", "torpedo", Easy),
+      word("weapon", "warhammer", Easy)
     ]
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::{rngs::StdRng, SeedableRng};
+
+    fn only(category: &str) -> WordFilter {
+        WordFilter {
+            categories: vec![category.to_string()],
+            ..WordFilter::default()
+        }
+    }
+
+    #[test]
+    fn using_up_a_category_only_puts_that_category_back() {
+        let mut rng = StdRng::seed_from_u64(7);
+        let mut deck = WordDeck::default();
+        let tools = words()
+            .iter()
+            .filter(|word| word.category == "tool")
+            .count();
+        let mut drawn = (0..20)
+            .map(|_| deck.draw(&WordFilter::default(), None, &mut rng).unwrap())
+            .filter(|word| word.category != "tool")
+            .collect::<Vec<_>>();
+        for _ in 0..tools * 2 {
+            deck.draw(&only("tool"), None, &mut rng).unwrap();
+        }
+        for _ in 0..200 {
+            let word = deck.draw(&WordFilter::default(), None, &mut rng).unwrap();
+            assert!(!drawn.contains(&word), "{:?} came up twice", word);
+            drawn.push(word);
+        }
+    }
+
+    #[test]
+    fn difficulty_varies_within_a_category() {
+        for category in [
+            "music band",
+            "pop culture",
+            "famous person",
+            "movies",
+            "animal",
+        ] {
+            let tiers = words()
+                .into_iter()
+                .filter(|word| word.category == category)
+                .map(|word| word.difficulty)
+                .collect::<HashSet<_>>();
+            assert!(tiers.len() > 1, "every {category} word is equally hard");
+        }
+    }
+
+    #[test]
+    fn draw_never_hands_back_the_current_word() {
+        let mut rng = StdRng::seed_from_u64(7);
+        let mut deck = WordDeck::default();
+        let mut current = deck.draw(&only("social"), None, &mut rng).unwrap();
+        for _ in 0..30 {
+            let word = deck
+                .draw(&only("social"), Some(&current), &mut rng)
+                .unwrap();
+            assert_ne!(word, current);
+            current = word;
+        }
+    }
+}
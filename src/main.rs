@@ -1,29 +1,57 @@
 use axum::http::{header::CONTENT_SECURITY_POLICY, HeaderValue};
+use history::GameStore;
 use socket::setup_socket;
 use socketioxide::{extract::SocketRef, SocketIo};
-use std::error::Error;
+use std::{env, error::Error, net::SocketAddr, sync::Arc};
 use tokio::signal::unix::{signal, SignalKind};
 use tower_http::{
-    services::{ServeDir, ServeFile},
+    services::ServeDir,
     set_header::SetResponseHeaderLayer,
 };
 use tracing::info;
 
+mod browser;
+mod config;
+mod encoding;
 mod game;
 mod game_server;
+mod history;
+mod http;
 mod lists;
+mod moderation;
+mod render;
+mod replay;
+mod room_id;
+mod room_log;
+mod settings;
+mod simplify;
+mod snapshot;
 mod socket;
 
 #[tokio::main]
 async fn main() -> Result<(), Box<dyn Error>> {
+    // `fake-artist replay <log>` rebuilds a room from its event log and
+    // prints the resulting game instead of starting the server
+    if let [_, command, path] = env::args().collect::<Vec<_>>().as_slice() {
+        if command == "replay" {
+            let log = serde_json::from_slice(&std::fs::read(path)?)?;
+            println!("{}", serde_json::to_string_pretty(&room_log::rebuild(&log))?);
+            return Ok(());
+        }
+    }
     start_tracing();
-    start_endpoint();
+    // Read the config and open the history up front so a bad setting fails
+    // at startup
+    config::config();
+    let store = history::open_store()?;
+    start_endpoint(store);
     let mut sigint = signal(SignalKind::interrupt())?;
     let mut sigterm = signal(SignalKind::terminate())?;
     tokio::select! {
         _ = sigint.recv() => {},
         _ = sigterm.recv() => {},
     };
+    snapshot::save_rooms().await;
     Ok(())
 }
 
@@ -31,16 +59,17 @@ fn start_tracing() {
     let subscriber = tracing_subscriber::FmtSubscriber::new();
     tracing::subscriber::set_global_default(subscriber).unwrap();
 }
-fn start_endpoint() {
-    tokio::spawn(async {
-        let (layer, io) = SocketIo::builder().build_layer();
+fn start_endpoint(store: Arc<dyn GameStore>) {
+    tokio::spawn(async move {
+        let (layer, io) = SocketIo::builder().with_state(store.clone()).build_layer();
         io.ns("/", |socket: SocketRef| {
             info!("Socket connected: {}", socket.id);
             setup_socket(socket)
         });
+        snapshot::restore_rooms(&io, &store).await;
 
         let app = axum::Router::new()
-            .route_service("/room/:room_id", ServeFile::new("frontend/dist/index.html"))
+            .merge(http::router(http::AppState { io, store }))
             .nest_service("/", ServeDir::new("frontend/dist"))
             .layer(layer)
             .layer(SetResponseHeaderLayer::overriding(
@@ -51,6 +80,11 @@ fn start_endpoint() {
             ));
         let listener = tokio::net::TcpListener::bind("0.0.0.0:4000").await.unwrap();
         info!("Listening on 0.0.0.0:4000");
-        axum::serve(listener, app).await.unwrap();
+        axum::serve(
+            listener,
+            app.into_make_service_with_connect_info::<SocketAddr>(),
+        )
+        .await
+        .unwrap();
     });
 }
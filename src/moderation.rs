@@ -0,0 +1,145 @@
+use itertools::Itertools;
+use unicode_normalization::UnicodeNormalization;
+
+use crate::config::config;
+
+pub const MAX_CHAT_LENGTH: usize = 280;
+pub const MAX_NAME_LENGTH: usize = 32;
+
+/// Invisible characters that can hide a blocked word or flip the direction
+/// of everything after them. The zero width joiner stays, emoji need it.
+fn is_hidden(c: char) -> bool {
+    matches!(
+        c,
+        '\u{200B}' | '\u{200C}' | '\u{200E}' | '\u{200F}'
+            | '\u{202A}'..='\u{202E}'
+            | '\u{2060}'..='\u{2064}'
+            | '\u{2066}'..='\u{2069}'
+            | '\u{FEFF}'
+    )
+}
+
+/// Tidies up text typed by a player before anyone else sees it: normalizes
+/// look-alike forms, drops control and invisible characters, collapses
+/// whitespace, cuts it to `max_length` characters and masks blocked words.
+pub fn clean(text: &str, max_length: usize) -> String {
+    let text = text
+        .nfkc()
+        .filter(|c| c.is_whitespace() || !(c.is_control() || is_hidden(*c)))
+        .collect::<String>();
+    // Collapsing whitespace also turns newlines and tabs into plain spaces
+    let text = text
+        .split_whitespace()
+        .join(" ")
+        .chars()
+        .take(max_length)
+        .collect::<String>();
+    mask_words(text.trim_end(), &config().blocked_words)
+}
+
+/// Replaces every letter of the given lowercase words or phrases with `*`.
+/// Matches ignore case but must stand on their own, so masking a word
+/// doesn't mask longer words that happen to contain it.
+pub fn mask_words(text: &str, words: &[String]) -> String {
+    let mut chars = text.chars().collect::<Vec<_>>();
+    // One lowercase char per char keeps positions lined up with `chars`
+    let lower = chars
+        .iter()
+        .map(|c| c.to_lowercase().next().unwrap_or(*c))
+        .collect::<Vec<_>>();
+    let is_boundary = |index: Option<&char>| !matches!(index, Some(c) if c.is_alphanumeric());
+    for word in words {
+        let word = word.chars().collect::<Vec<_>>();
+        if word.is_empty() || word.len() > lower.len() {
+            continue;
+        }
+        for start in 0..=lower.len() - word.len() {
+            let end = start + word.len();
+            if lower[start..end] == word[..]
+                && is_boundary(start.checked_sub(1).and_then(|i| lower.get(i)))
+                && is_boundary(lower.get(end))
+            {
+                for c in &mut chars[start..end] {
+                    if !c.is_whitespace() {
+                        *c = '*';
+                    }
+                }
+            }
+        }
+    }
+    chars.into_iter().collect()
+}
+
+/// The ways a player might type `word` in chat: any case, plural, and for
+/// phrases with hyphens or without spaces. Possessives need no variant since
+/// the apostrophe already ends the word.
+pub fn word_variants(word: &str) -> Vec<String> {
+    let word = word.nfkc().collect::<String>().to_lowercase();
+    let word = word.split_whitespace().join(" ");
+    let mut spellings = vec![word.clone()];
+    if word.contains(' ') {
+        spellings.push(word.replace(' ', "-"));
+        spellings.push(word.replace(' ', ""));
+    }
+    let mut variants = vec![];
+    for spelling in spellings {
+        if let Some(stem) = spelling.strip_suffix('y') {
+            variants.push(format!("{stem}ies"));
+        }
+        variants.push(format!("{spelling}s"));
+        variants.push(format!("{spelling}es"));
+        variants.push(spelling);
+    }
+    variants
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn blocked(words: &[&str]) -> Vec<String> {
+        words.iter().map(|word| word.to_string()).collect()
+    }
+
+    #[test]
+    fn hidden_and_bidi_characters_are_stripped() {
+        assert_eq!(
+            clean("a\u{200B}b\u{202E}c\u{2066}d\u{FEFF}e\u{0007}", 32),
+            "abcde"
+        );
+        assert_eq!(clean("  two\n\twords  ", 32), "two words");
+        // Emoji sequences need the zero width joiner
+        assert_eq!(clean("👩\u{200D}🎨", 32), "👩\u{200D}🎨");
+        assert_eq!(clean("ｆｕｌｌｗｉｄｔｈ", 4), "full");
+    }
+
+    #[test]
+    fn blocked_words_are_masked_only_on_their_own() {
+        let words = blocked(&["cat", "hot dog"]);
+        assert_eq!(mask_words("Cat, concat, cats", &words), "***, concat, cats");
+        assert_eq!(mask_words("a HOT DOG!", &words), "a *** ***!");
+        assert_eq!(mask_words("hotdog", &words), "hotdog");
+        assert_eq!(mask_words("ca", &words), "ca");
+    }
+
+    #[test]
+    fn masking_keeps_positions_when_case_changes_length() {
+        // 'İ' lowercases to two chars, which mustn't shift what gets masked
+        let words = blocked(&["cat"]);
+        assert_eq!(mask_words("İ cat", &words), "İ ***");
+    }
+
+    #[test]
+    fn variants_cover_plurals_and_joined_phrases() {
+        let words = word_variants("Hot Dog");
+        assert_eq!(
+            mask_words("hot dogs, hot-dog and hotdoges", &words),
+            "*** ****, ******* and ********"
+        );
+        let words = word_variants("butterfly");
+        assert_eq!(
+            mask_words("butterflies and butterfly's", &words),
+            "*********** and *********'s"
+        );
+    }
+}
@@ -0,0 +1,100 @@
+use std::fmt::Write;
+
+use tiny_skia::{Color, LineCap, LineJoin, Paint, PathBuilder, Pixmap, Stroke, Transform};
+
+use crate::game::{Curve, Tool, CANVAS_HEIGHT};
+
+/// Points are normalized to the canvas width.
+const WIDTH: f32 = 1600.0;
+const HEIGHT: f32 = WIDTH * CANVAS_HEIGHT;
+
+pub fn svg(curves: &[Curve]) -> String {
+    let mut svg = format!(
+        r#"<svg xmlns="http://www.w3.org/2000/svg" viewBox="0 0 {WIDTH} {HEIGHT}" width="{WIDTH}" height="{HEIGHT}"><rect width="100%" height="100%" fill="white"/>"#
+    );
+    for curve in curves.iter().filter(|curve| !curve.points.is_empty()) {
+        let path = curve
+            .points
+            .iter()
+            .enumerate()
+            .fold(String::new(), |mut path, (i, point)| {
+                let command = if i == 0 { 'M' } else { 'L' };
+                let _ = write!(
+                    path,
+                    "{command}{:.1} {:.1}",
+                    point.x * WIDTH,
+                    point.y * WIDTH
+                );
+                path
+            });
+        let opacity = if curve.style.tool == Tool::Highlighter {
+            0.4
+        } else {
+            1.0
+        };
+        let _ = write!(
+            svg,
+            r#"<path d="{path}" fill="none" stroke="{}" stroke-width="{}" stroke-opacity="{opacity}" stroke-linecap="round" stroke-linejoin="round"/>"#,
+            escape(&curve.author.color),
+            curve.style.width,
+        );
+    }
+    svg.push_str("</svg>");
+    svg
+}
+
+/// Rasterizes the drawing to a PNG `width` pixels wide, keeping the 16:9
+/// canvas shape.
+pub fn png(curves: &[Curve], width: u32) -> Vec<u8> {
+    let height = (width as f32 * HEIGHT / WIDTH).round() as u32;
+    let mut pixmap = Pixmap::new(width, height.max(1)).unwrap();
+    pixmap.fill(Color::WHITE);
+    let scale = width as f32;
+    for curve in curves {
+        let mut path = PathBuilder::new();
+        for (i, point) in curve.points.iter().enumerate() {
+            if i == 0 {
+                path.move_to(point.x * scale, point.y * scale);
+            } else {
+                path.line_to(point.x * scale, point.y * scale);
+            }
+        }
+        let Some(path) = path.finish() else {
+            continue;
+        };
+        let mut paint = Paint::default();
+        paint.set_color(parse_color(&curve.author.color));
+        if curve.style.tool == Tool::Highlighter {
+            paint.shader.apply_opacity(0.4);
+        }
+        let stroke = Stroke {
+            width: (curve.style.width * scale / WIDTH).max(1.0),
+            line_cap: LineCap::Round,
+            line_join: LineJoin::Round,
+            ..Stroke::default()
+        };
+        pixmap.stroke_path(&path, &paint, &stroke, Transform::identity(), None);
+    }
+    pixmap.encode_png().unwrap()
+}
+
+/// Parses `#RRGGBB`, drawing anything else in black.
+fn parse_color(color: &str) -> Color {
+    let channel = |i: usize| {
+        color
+            .get(i..i + 2)
+            .and_then(|hex| u8::from_str_radix(hex, 16).ok())
+    };
+    match (color.starts_with('#'), channel(1), channel(3), channel(5)) {
+        (true, Some(r), Some(g), Some(b)) if color.len() == 7 => Color::from_rgba8(r, g, b, 255),
+        _ => Color::BLACK,
+    }
+}
+
+/// Escapes text for use inside XML or HTML attributes.
+pub(crate) fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('"', "&quot;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+}
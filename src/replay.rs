@@ -0,0 +1,58 @@
+use std::time::Duration;
+
+use socketioxide::extract::SocketRef;
+use tokio::time::{sleep_until, Instant};
+
+use crate::{
+    encoding::EncodedPoints,
+    game::{Curve, Point},
+    socket::ReplayDrawEvent,
+};
+
+/// Points due within this window of each other go out in one event.
+const REPLAY_TICK: Duration = Duration::from_millis(50);
+const MIN_SPEED: f32 = 0.25;
+const MAX_SPEED: f32 = 16.0;
+
+/// Streams the strokes back to one socket with their original timing,
+/// divided by `speed`. Strokes play back to back, each starting when the
+/// previous one finished.
+pub async fn replay(socket: SocketRef, curves: Vec<Curve>, speed: f32) {
+    let speed = if speed.is_finite() {
+        speed.clamp(MIN_SPEED, MAX_SPEED)
+    } else {
+        1.0
+    };
+    for (stroke, curve) in curves.iter().enumerate() {
+        let started = Instant::now();
+        let mut batch: Vec<Point> = vec![];
+        for point in &curve.points {
+            let due = started + Duration::from_millis(point.t as u64).div_f32(speed);
+            if due > Instant::now() + REPLAY_TICK {
+                if !emit(&socket, stroke, curve, &mut batch) {
+                    return;
+                }
+                sleep_until(due).await;
+            }
+            batch.push(point.clone());
+        }
+        if !emit(&socket, stroke, curve, &mut batch) {
+            return;
+        }
+    }
+    socket.emit("replay_end", ()).ok();
+}
+
+/// Sends the batched points, returning false once the socket is gone.
+fn emit(socket: &SocketRef, stroke: usize, curve: &Curve, batch: &mut Vec<Point>) -> bool {
+    if batch.is_empty() {
+        return socket.connected();
+    }
+    let event = ReplayDrawEvent {
+        stroke,
+        points: EncodedPoints::encode(batch),
+        style: curve.style.clone(),
+    };
+    batch.clear();
+    socket.emit("replay_draw", event).is_ok()
+}
@@ -0,0 +1,93 @@
+use std::{collections::BTreeMap, sync::Mutex};
+
+use rand::Rng;
+use uuid::Uuid;
+
+/// Alphabet used by the frontend's `short-uuid` links (flickrBase58).
+const SHORT_ALPHABET: &[u8] = b"123456789abcdefghijkmnopqrstuvwxyzABCDEFGHJKLMNPQRSTUVWXYZ";
+
+/// Letters room codes are made of, leaving out I, L and O which are easily
+/// mistaken for each other or for digits.
+const CODE_ALPHABET: &[u8] = b"ABCDEFGHJKMNPQRSTUVWXYZ";
+pub const CODE_LENGTH: usize = 5;
+
+/// Length of a hyphenated UUID.
+const UUID_LENGTH: usize = 36;
+/// Length of a short id, enough base58 digits for any UUID.
+const SHORT_LENGTH: usize = 22;
+
+/// Which room each code currently belongs to.
+static CODES: Mutex<BTreeMap<String, Uuid>> = Mutex::new(BTreeMap::new());
+
+/// Accepts a room id as a plain UUID, in the short form used in
+/// `/room/:room_id` links, or as the code of a running room. Anything else,
+/// including other UUID spellings, is rejected.
+pub fn parse(room_id: &str) -> Option<Uuid> {
+    match room_id.len() {
+        CODE_LENGTH => CODES
+            .lock()
+            .unwrap()
+            .get(&room_id.to_ascii_uppercase())
+            .copied(),
+        UUID_LENGTH => Uuid::try_parse(room_id).ok(),
+        SHORT_LENGTH => {
+            let mut value: u128 = 0;
+            for byte in room_id.bytes() {
+                let digit = SHORT_ALPHABET.iter().position(|c| *c == byte)?;
+                value = value.checked_mul(58)?.checked_add(digit as u128)?;
+            }
+            Some(Uuid::from_u128(value))
+        }
+        _ => None,
+    }
+}
+
+/// Gives the room a code no other running room has, keeping `preferred` if
+/// it's still free so codes survive a restart.
+pub fn claim_code(room_id: Uuid, preferred: &str) -> String {
+    let mut codes = CODES.lock().unwrap();
+    if preferred.len() == CODE_LENGTH && !codes.contains_key(preferred) {
+        codes.insert(preferred.to_string(), room_id);
+        return preferred.to_string();
+    }
+    let mut rng = rand::thread_rng();
+    loop {
+        let code = (0..CODE_LENGTH)
+            .map(|_| CODE_ALPHABET[rng.gen_range(0..CODE_ALPHABET.len())] as char)
+            .collect::<String>();
+        if !codes.contains_key(&code) {
+            codes.insert(code.clone(), room_id);
+            return code;
+        }
+    }
+}
+
+pub fn release_code(room_id: Uuid, code: &str) {
+    let mut codes = CODES.lock().unwrap();
+    if codes.get(code) == Some(&room_id) {
+        codes.remove(code);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_only_known_id_forms() {
+        let id = Uuid::from_u128(0x0123_4567_89ab_cdef_0123_4567_89ab_cdef);
+        assert_eq!(parse(&id.hyphenated().to_string()), Some(id));
+        assert_eq!(parse(&id.simple().to_string()), None);
+        assert_eq!(parse(&id.braced().to_string()), None);
+        assert_eq!(parse(&id.urn().to_string()), None);
+        assert_eq!(parse("1111111111111111111112"), Some(Uuid::from_u128(1)));
+        assert_eq!(parse("2"), None);
+        assert_eq!(parse(""), None);
+        assert_eq!(parse("zzzzzzzzzzzzzzzzzzzzzz"), None);
+
+        let code = claim_code(id, "QWERT");
+        assert_eq!(parse(&code.to_ascii_lowercase()), Some(id));
+        release_code(id, &code);
+        assert_eq!(parse(&code), None);
+    }
+}
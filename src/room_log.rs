@@ -0,0 +1,133 @@
+use serde::{Deserialize, Serialize};
+use uuid::Uuid;
+
+use crate::{
+    game::{Game, Player, Point, StrokeStyle},
+    game_server::{Room, RoomSnapshot},
+    lists::Word,
+    settings::RoomSettings,
+};
+
+/// A state change a room accepted, as recorded in its log.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+#[serde(tag = "type")]
+pub enum Command {
+    Join {
+        resume: Option<Uuid>,
+    },
+    UpdatePlayer {
+        player: Player,
+    },
+    ChangeColor {
+        player: Player,
+        color: String,
+    },
+    Leave {
+        player: Player,
+    },
+    UpdateSettings {
+        /// Missing when the settings came in over the API.
+        #[serde(default)]
+        player: Option<Player>,
+        settings: RoomSettings,
+    },
+    SubmitWords {
+        player: Player,
+        words: Vec<Word>,
+    },
+    StartGame,
+    VoteReroll {
+        player: Player,
+    },
+    Rematch,
+    DrawStart {
+        player: Player,
+        style: StrokeStyle,
+    },
+    Draw {
+        player: Player,
+        points: Vec<Point>,
+    },
+    DrawEnd {
+        player: Player,
+    },
+    VoteFake {
+        player: Player,
+        target: Player,
+    },
+    Chat {
+        player: Player,
+        message: String,
+    },
+    DropAbsent,
+    /// The room came back from a snapshot, with everyone disconnected and
+    /// a fresh seed.
+    Restored {
+        seed: u64,
+    },
+}
+
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct LogEntry {
+    pub seq: u64,
+    /// Milliseconds on the room's clock.
+    pub at: u64,
+    pub command: Command,
+}
+
+/// Everything needed to rebuild a room: the seed its RNG started from and
+/// every command it accepted, in order. Each game gets a log of its own,
+/// picking up from the room as the last one left it.
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct RoomLog {
+    pub room_id: Uuid,
+    /// How many logs the room went through before this one.
+    #[serde(default)]
+    pub part: u32,
+    pub seed: u64,
+    /// The room when this log took over, or nothing if the log goes back to
+    /// when the room opened.
+    #[serde(default)]
+    pub start: Option<Box<RoomSnapshot>>,
+    pub entries: Vec<LogEntry>,
+}
+
+impl RoomLog {
+    pub fn new(room_id: Uuid, seed: u64) -> Self {
+        RoomLog {
+            room_id,
+            part: 0,
+            seed,
+            start: None,
+            entries: vec![],
+        }
+    }
+    /// The log that follows this one, starting from `start`.
+    pub fn next(&self, seed: u64, start: RoomSnapshot) -> Self {
+        RoomLog {
+            part: self.part + 1,
+            start: Some(Box::new(start)),
+            ..RoomLog::new(self.room_id, seed)
+        }
+    }
+    pub fn append(&mut self, at: u64, command: Command) {
+        self.entries.push(LogEntry {
+            seq: self.entries.len() as u64,
+            at,
+            command,
+        });
+    }
+}
+
+/// Replays a log against a fresh room seeded the same way, ending in the
+/// same game the original room had after its last entry.
+pub fn rebuild(log: &RoomLog) -> Game {
+    let mut room = match &log.start {
+        Some(start) => Room::resumed(start.as_ref().clone(), log.seed),
+        None => Room::seeded(log.room_id, log.seed),
+    };
+    for entry in &log.entries {
+        room.replay(entry);
+    }
+    room.game().clone()
+}
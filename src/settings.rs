@@ -0,0 +1,69 @@
+use serde::{Deserialize, Serialize};
+use ts_rs::TS;
+
+use crate::{game::GameError, lists::WordFilter};
+
+pub const MAX_ROOM_NAME_LENGTH: usize = 40;
+pub const MAX_PASSCODE_LENGTH: usize = 64;
+
+/// Options chosen in the lobby that apply to every game played in a room.
+#[derive(Debug, Deserialize, Serialize, TS, Clone)]
+#[serde(default)]
+#[ts(export)]
+pub struct RoomSettings {
+    pub word_filter: WordFilter,
+    /// How many times per game the real artists may vote to swap the word.
+    pub max_rerolls: u8,
+    /// When non-zero, every player submits this many words in the lobby and
+    /// the game draws from them instead of the built-in list.
+    pub words_per_player: u8,
+    /// Finished games are saved to the public gallery unless a room opts out.
+    pub keep_history: bool,
+    /// Public rooms are listed in the room browser for anyone to join.
+    pub public: bool,
+    /// What the room browser calls the room.
+    pub name: String,
+    /// The language players are expected to chat in, as a code like "en".
+    pub language: String,
+    /// When set, joining the room takes this passcode.
+    pub passcode: Option<String>,
+}
+
+impl Default for RoomSettings {
+    fn default() -> Self {
+        RoomSettings {
+            word_filter: WordFilter::default(),
+            max_rerolls: 1,
+            words_per_player: 0,
+            keep_history: true,
+            public: false,
+            name: String::new(),
+            language: "en".to_string(),
+            passcode: None,
+        }
+    }
+}
+
+impl RoomSettings {
+    pub fn validate(&self) -> Result<(), GameError> {
+        if self.name.chars().count() > MAX_ROOM_NAME_LENGTH {
+            return Err(GameError::InvalidRoomName);
+        }
+        let language_valid = (2..=8).contains(&self.language.len())
+            && self
+                .language
+                .chars()
+                .all(|c| c.is_ascii_alphanumeric() || c == '-');
+        if !language_valid {
+            return Err(GameError::InvalidLanguage);
+        }
+        let passcode_valid = match &self.passcode {
+            Some(passcode) => (1..=MAX_PASSCODE_LENGTH).contains(&passcode.chars().count()),
+            None => true,
+        };
+        if !passcode_valid {
+            return Err(GameError::InvalidPasscode);
+        }
+        Ok(())
+    }
+}
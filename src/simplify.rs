@@ -0,0 +1,107 @@
+use crate::game::Point;
+
+/// Ramer–Douglas–Peucker: drops every point that lies within `tolerance` of
+/// the line through the points kept around it. The first and last points are
+/// always kept.
+pub fn simplify(points: &[Point], tolerance: f32) -> Vec<Point> {
+    if points.len() < 3 || tolerance <= 0.0 {
+        return points.to_vec();
+    }
+    let mut keep = vec![false; points.len()];
+    keep[0] = true;
+    keep[points.len() - 1] = true;
+    let mut ranges = vec![(0, points.len() - 1)];
+    while let Some((start, end)) = ranges.pop() {
+        let (index, distance) = (start + 1..end)
+            .map(|i| {
+                (
+                    i,
+                    distance_to_segment(&points[i], &points[start], &points[end]),
+                )
+            })
+            .fold((start, 0.0), |farthest, candidate| {
+                if candidate.1 > farthest.1 {
+                    candidate
+                } else {
+                    farthest
+                }
+            });
+        if distance > tolerance {
+            keep[index] = true;
+            ranges.push((start, index));
+            ranges.push((index, end));
+        }
+    }
+    points
+        .iter()
+        .zip(keep)
+        .filter(|(_, keep)| *keep)
+        .map(|(point, _)| point.clone())
+        .collect()
+}
+
+fn distance_to_segment(point: &Point, start: &Point, end: &Point) -> f32 {
+    let (dx, dy) = (end.x - start.x, end.y - start.y);
+    let length_squared = dx * dx + dy * dy;
+    let t = if length_squared == 0.0 {
+        0.0
+    } else {
+        (((point.x - start.x) * dx + (point.y - start.y) * dy) / length_squared).clamp(0.0, 1.0)
+    };
+    let (closest_x, closest_y) = (start.x + t * dx, start.y + t * dy);
+    ((point.x - closest_x).powi(2) + (point.y - closest_y).powi(2)).sqrt()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn points(coordinates: &[(f32, f32)]) -> Vec<Point> {
+        coordinates
+            .iter()
+            .enumerate()
+            .map(|(t, &(x, y))| Point { x, y, t: t as u32 })
+            .collect()
+    }
+
+    fn coordinates(points: &[Point]) -> Vec<(f32, f32)> {
+        points.iter().map(|point| (point.x, point.y)).collect()
+    }
+
+    #[test]
+    fn collinear_points_collapse_to_the_endpoints() {
+        let line = points(&[(0.0, 0.0), (0.1, 0.1), (0.2, 0.2), (0.3, 0.3), (0.4, 0.4)]);
+        let simplified = simplify(&line, 0.001);
+        assert_eq!(coordinates(&simplified), [(0.0, 0.0), (0.4, 0.4)]);
+        assert_eq!(simplified[1].t, 4);
+    }
+
+    #[test]
+    fn corners_above_the_tolerance_are_kept() {
+        let corner = points(&[(0.0, 0.0), (0.1, 0.0), (0.2, 0.0), (0.2, 0.1), (0.2, 0.2)]);
+        assert_eq!(
+            coordinates(&simplify(&corner, 0.01)),
+            [(0.0, 0.0), (0.2, 0.0), (0.2, 0.2)]
+        );
+        // Within the tolerance the corner goes too
+        assert_eq!(
+            coordinates(&simplify(&corner, 0.5)),
+            [(0.0, 0.0), (0.2, 0.2)]
+        );
+    }
+
+    #[test]
+    fn points_beyond_the_segment_end_are_measured_to_it() {
+        // Lies on the line through the endpoints, but past the last one
+        let overshoot = points(&[(0.0, 0.0), (0.3, 0.0), (0.2, 0.0)]);
+        assert_eq!(coordinates(&simplify(&overshoot, 0.05)).len(), 3);
+    }
+
+    #[test]
+    fn short_strokes_and_zero_tolerance_are_left_alone() {
+        let line = points(&[(0.0, 0.0), (0.1, 0.1), (0.2, 0.2)]);
+        assert_eq!(coordinates(&simplify(&line, 0.0)), coordinates(&line));
+        let short = points(&[(0.0, 0.0), (0.1, 0.1)]);
+        assert_eq!(coordinates(&simplify(&short, 1.0)), coordinates(&short));
+    }
+}
@@ -0,0 +1,63 @@
+use std::sync::Arc;
+
+use ractor::{call, Actor, ActorRef};
+use socketioxide::SocketIo;
+use tracing::{error, info};
+
+use crate::{
+    config::config,
+    game_server::{GameServer, Message, Room, RoomSnapshot},
+    history::GameStore,
+};
+
+/// Writes every live room to the snapshot file.
+pub async fn save_rooms() {
+    let mut snapshots = vec![];
+    for name in ractor::registry::registered() {
+        if !name.starts_with("room:") {
+            continue;
+        }
+        let Some(room) = ractor::registry::where_is(name.clone()).map(ActorRef::<Message>::from)
+        else {
+            continue;
+        };
+        match call!(room, Message::Snapshot) {
+            Ok(snapshot) => snapshots.push(snapshot),
+            Err(err) => error!("Failed to snapshot {}: {}", name, err),
+        }
+    }
+    let json = match serde_json::to_vec(&snapshots) {
+        Ok(json) => json,
+        Err(err) => return error!("Failed to serialize rooms: {}", err),
+    };
+    match tokio::fs::write(&config().snapshot_path, json).await {
+        Ok(()) => info!("Saved {} rooms", snapshots.len()),
+        Err(err) => error!("Failed to save rooms: {}", err),
+    }
+}
+
+/// Starts the rooms saved by the last shutdown, if any. The snapshot is
+/// removed once read so the same rooms never come back twice.
+pub async fn restore_rooms(io: &SocketIo, store: &Arc<dyn GameStore>) {
+    let path = &config().snapshot_path;
+    let Ok(json) = tokio::fs::read(path).await else {
+        return;
+    };
+    tokio::fs::remove_file(path).await.ok();
+    let snapshots: Vec<RoomSnapshot> = match serde_json::from_slice(&json) {
+        Ok(snapshots) => snapshots,
+        Err(err) => return error!("Failed to read saved rooms: {}", err),
+    };
+    let count = snapshots.len();
+    for snapshot in snapshots {
+        let name = format!("room:{}", snapshot.id);
+        let server = GameServer {
+            io: io.clone(),
+            store: store.clone(),
+        };
+        if let Err(err) = Actor::spawn(Some(name), server, Room::from(snapshot)).await {
+            error!("Failed to restore room: {}", err);
+        }
+    }
+    info!("Restored {} rooms", count);
+}
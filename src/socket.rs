@@ -1,55 +1,176 @@
-use std::{collections::HashMap, future::Future};
+use std::{
+    collections::{BTreeMap, HashMap},
+    future::Future,
+    net::{IpAddr, Ipv4Addr, SocketAddr},
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
+};
+
+use axum::extract::ConnectInfo;
 
 use ractor::{call, cast, Actor, ActorRef};
 use serde::{Deserialize, Serialize};
 use socketioxide::{
-    extract::{Data, SocketRef},
+    extract::{Data, SocketRef, State},
     SocketIo,
 };
-use tracing::info;
+use tracing::{info, warn};
 use ts_rs::TS;
 use uuid::Uuid;
 
 use crate::{
-    game::{ChatMessage, Game, Player, Point},
-    game_server::{GameServer, Message},
+    browser,
+    config::{config, RateLimit},
+    encoding::EncodedPoints,
+    game::{ChatMessage, Game, GameError, Player, Point, StrokeStyle, Tool},
+    game_server::{GameServer, Joined, Message, Room},
+    history::GameStore,
+    lists::{self, Word},
+    replay::replay,
+    room_id,
+    settings::RoomSettings,
 };
 
 #[derive(Serialize, TS)]
 struct JoinEvent {
     current_player_id: Option<Uuid>,
+    /// Sent only to the player who just joined, to keep to themselves.
+    resume_token: Option<Uuid>,
+    room_code: Option<String>,
     game_state: Option<Game>,
+    settings: Option<RoomSettings>,
+    colors: Option<Vec<&'static str>>,
     players: Option<Vec<Player>>,
     spectators: Option<Vec<Player>>,
+    /// Recent chat, sent to the player who just joined.
+    chat: Option<Vec<ChatMessage>>,
+}
+/// `room_id` is the room's UUID, its short link form, or its code.
+/// `resume_token` takes back a player from before a server restart,
+/// `passcode` is needed for rooms that have one.
+#[derive(Deserialize, TS)]
+struct JoinRequest {
+    room_id: String,
+    #[serde(default)]
+    resume_token: Option<Uuid>,
+    #[serde(default)]
+    passcode: Option<String>,
+}
+
+/// Older clients send just the room id.
+#[derive(Deserialize, TS)]
+#[serde(untagged)]
+enum JoinIn {
+    RoomId(String),
+    Request(JoinRequest),
+}
+
+impl JoinIn {
+    fn into_request(self) -> JoinRequest {
+        match self {
+            JoinIn::RoomId(room_id) => JoinRequest {
+                room_id,
+                resume_token: None,
+                passcode: None,
+            },
+            JoinIn::Request(request) => request,
+        }
+    }
 }
 #[derive(Serialize, TS)]
-struct NextTurnEvent {
-    current_player_index: usize,
-    is_last_turn: bool,
+pub struct NextTurnEvent {
+    pub current_player_index: usize,
+    pub is_last_turn: bool,
 }
 #[derive(Serialize, TS)]
-struct DrawEvent {
-    position: Point,
+pub struct DrawEvent {
+    pub points: EncodedPoints,
+    pub style: StrokeStyle,
+}
+
+/// Clients may send a single point, a plain batch, or an encoded batch.
+#[derive(Deserialize, TS)]
+#[serde(untagged)]
+enum DrawIn {
+    Point(Point),
+    Points(Vec<Point>),
+    Encoded(EncodedPoints),
+}
+
+impl DrawIn {
+    fn into_points(self) -> Option<Vec<Point>> {
+        match self {
+            DrawIn::Point(point) => Some(vec![point]),
+            DrawIn::Points(points) => Some(points),
+            DrawIn::Encoded(encoded) => encoded.decode(),
+        }
+    }
 }
 #[derive(Serialize, TS)]
 struct VotesTotalEvent {
     votes: HashMap<Uuid, Uuid>,
 }
 
+#[derive(Serialize, TS)]
+struct RerollEvent {
+    word: Word,
+    reroll_votes: Vec<Uuid>,
+    rerolls_left: u8,
+}
+#[derive(Serialize, TS)]
+pub struct ErrorEvent {
+    message: String,
+}
+
+impl<E: std::fmt::Display> From<E> for ErrorEvent {
+    fn from(error: E) -> Self {
+        ErrorEvent {
+            message: error.to_string(),
+        }
+    }
+}
+
+/// Sent when the pen goes down; `color` defaults to the player's own color.
+#[derive(Deserialize, TS)]
+struct DrawStartEvent {
+    width: f32,
+    color: Option<String>,
+    #[serde(default)]
+    tool: Tool,
+}
+
 #[derive(Deserialize, TS)]
 struct VoteFakeEvent {
     target: Player,
 }
 
+/// Asks for the finished game's strokes; `speed` defaults to real time.
+#[derive(Deserialize, TS)]
+struct ReplayEvent {
+    speed: Option<f32>,
+}
+#[derive(Serialize, TS)]
+pub struct ReplayDrawEvent {
+    /// Which stroke the points belong to, counting from zero. Turns ended
+    /// without drawing have none, so this isn't the turn number.
+    pub stroke: usize,
+    pub points: EncodedPoints,
+    pub style: StrokeStyle,
+}
+
 #[allow(dead_code)]
 #[derive(Deserialize, TS)]
 #[serde(tag = "type")]
 #[ts(export)]
 enum EventIn {
+    Join(JoinIn),
+    DrawStart(DrawStartEvent),
+    Draw(DrawIn),
     VoteFake(VoteFakeEvent),
+    Replay(ReplayEvent),
 }
 
-#[allow(dead_code)]
+#[allow(dead_code, clippy::large_enum_variant)]
 #[derive(Serialize, TS)]
 #[serde(tag = "type")]
 #[ts(export)]
@@ -58,10 +179,14 @@ enum EventOut {
     NextTurn(NextTurnEvent),
     DrawEvent(DrawEvent),
     VoteFake(VotesTotalEvent),
+    Reroll(RerollEvent),
+    Settings(RoomSettings),
+    ReplayDraw(ReplayDrawEvent),
+    Error(ErrorEvent),
 }
 
 async fn get_or_create_actor<T: 'static, F, Fut>(
-    socket: &mut SocketRef,
+    socket: &SocketRef,
     id: Uuid,
     create_f: F,
 ) -> ActorRef<T>
@@ -71,30 +196,189 @@ where
 {
     match socket.extensions.get::<ActorRef<T>>() {
         Some(actor_ref) => actor_ref,
-        None => {
-            let actor = match ractor::registry::where_is(format!("room:{}", id)) {
-                Some(actor_cell) => actor_cell.into(),
-                None => create_f().await,
-            };
-            socket.extensions.insert(actor.clone());
-            actor
+        None => match ractor::registry::where_is(format!("room:{}", id)) {
+            Some(actor_cell) => actor_cell.into(),
+            None => create_f().await,
+        },
+    }
+}
+
+fn emit_error(socket: &SocketRef, error: impl std::fmt::Display) {
+    socket.emit("game_error", ErrorEvent::from(error)).ok();
+}
+
+/// Wrong passcodes one address may send a room per window before its joins
+/// to that room are turned away.
+const MAX_PASSCODE_ATTEMPTS: u32 = 5;
+const PASSCODE_WINDOW: Duration = Duration::from_secs(60);
+
+/// When each address's current window for a room started and how many wrong
+/// passcodes it has sent that room since. Keeping rooms apart means one
+/// address, possibly a proxy many players share, can only lock itself out
+/// of the room it's guessing at.
+static PASSCODE_ATTEMPTS: Mutex<BTreeMap<(IpAddr, Uuid), (Instant, u32)>> =
+    Mutex::new(BTreeMap::new());
+
+fn client_ip(socket: &SocketRef) -> IpAddr {
+    socket
+        .req_parts()
+        .extensions
+        .get::<ConnectInfo<SocketAddr>>()
+        .map(|ConnectInfo(addr)| addr.ip())
+        .unwrap_or(IpAddr::V4(Ipv4Addr::UNSPECIFIED))
+}
+
+fn passcode_locked_out(ip: IpAddr, room_id: Uuid) -> bool {
+    let attempts = PASSCODE_ATTEMPTS.lock().unwrap();
+    matches!(attempts.get(&(ip, room_id)), Some((started, count))
+        if started.elapsed() < PASSCODE_WINDOW && *count >= MAX_PASSCODE_ATTEMPTS)
+}
+
+fn record_wrong_passcode(ip: IpAddr, room_id: Uuid) {
+    let mut attempts = PASSCODE_ATTEMPTS.lock().unwrap();
+    attempts.retain(|_, (started, _)| started.elapsed() < PASSCODE_WINDOW);
+    let (_, count) = attempts.entry((ip, room_id)).or_insert((Instant::now(), 0));
+    *count += 1;
+    if *count == MAX_PASSCODE_ATTEMPTS {
+        warn!("Too many wrong passcodes for room {} from {}", room_id, ip);
+    }
+}
+
+struct TokenBucket {
+    tokens: f32,
+    refilled: Instant,
+    /// Whether the last event was turned away, so a flood is reported once
+    /// rather than answered event by event.
+    rejecting: bool,
+}
+
+/// What a bucket makes of an event.
+#[derive(Debug, PartialEq)]
+enum Verdict {
+    Allow,
+    /// The first event turned away in a row, worth reporting.
+    Reject,
+    /// Turned away like the event before it.
+    RejectQuietly,
+}
+
+impl TokenBucket {
+    fn full(limit: RateLimit, now: Instant) -> Self {
+        TokenBucket {
+            tokens: limit.burst,
+            refilled: now,
+            rejecting: false,
+        }
+    }
+    fn take(&mut self, limit: RateLimit, now: Instant) -> Verdict {
+        let elapsed = now.saturating_duration_since(self.refilled).as_secs_f32();
+        self.tokens = (self.tokens + elapsed * limit.per_second).min(limit.burst);
+        self.refilled = now;
+        if self.tokens >= 1.0 {
+            self.tokens -= 1.0;
+            self.rejecting = false;
+            Verdict::Allow
+        } else if self.rejecting {
+            Verdict::RejectQuietly
+        } else {
+            self.rejecting = true;
+            Verdict::Reject
+        }
+    }
+}
+
+/// A socket's token buckets, one per event name.
+#[derive(Clone, Default)]
+struct RateLimiter(Arc<Mutex<HashMap<&'static str, TokenBucket>>>);
+
+/// Takes a token for `event` from the socket's bucket, returning whether
+/// the event should be dropped. The first event dropped in a row gets an
+/// error back and is logged.
+fn throttled(socket: &SocketRef, event: &'static str) -> bool {
+    let Some(RateLimiter(buckets)) = socket.extensions.get::<RateLimiter>() else {
+        return false;
+    };
+    let limit = config().rate_limit(event);
+    let now = Instant::now();
+    let mut buckets = buckets.lock().unwrap();
+    let bucket = buckets
+        .entry(event)
+        .or_insert_with(|| TokenBucket::full(limit, now));
+    match bucket.take(limit, now) {
+        Verdict::Allow => false,
+        Verdict::Reject => {
+            warn!(
+                "Socket {} from {} is sending too many {} events",
+                socket.id,
+                client_ip(socket),
+                event
+            );
+            emit_error(socket, GameError::RateLimited);
+            true
         }
+        Verdict::RejectQuietly => true,
     }
 }
 
 pub fn setup_socket(socket: SocketRef) {
+    // Lets room actors address or skip this socket by id when broadcasting
+    socket.join(socket.id).ok();
+    socket.extensions.insert(RateLimiter::default());
     socket.on(
         "join",
-        |mut socket: SocketRef, Data(room_id): Data<Uuid>| async move {
-            let player = Player::random();
-            let game_server = get_or_create_actor(&mut socket, room_id, || async {
-                Actor::spawn(Some(format!("room:{}", room_id)), GameServer, ())
-                    .await
-                    .unwrap()
-                    .0
+        |io: SocketIo,
+         socket: SocketRef,
+         Data(join): Data<JoinIn>,
+         State(store): State<Arc<dyn GameStore>>| async move {
+            if throttled(&socket, "join") {
+                return;
+            }
+            let JoinRequest {
+                room_id,
+                resume_token,
+                passcode,
+            } = join.into_request();
+            // Codes only exist for running rooms, anything else starts one
+            let Some(room_id) = room_id::parse(&room_id) else {
+                emit_error(&socket, GameError::UnknownRoom);
+                return;
+            };
+            let game_server = get_or_create_actor(&socket, room_id, || async {
+                Actor::spawn(
+                    Some(format!("room:{}", room_id)),
+                    GameServer { io, store },
+                    Room::new(room_id),
+                )
+                .await
+                .unwrap()
+                .0
             })
             .await;
-            let game_state = call!(game_server, Message::Join, player.clone()).unwrap();
+            let ip = client_ip(&socket);
+            if passcode_locked_out(ip, room_id)
+                && call!(game_server, Message::GetStatus).is_ok_and(|status| status.locked)
+            {
+                emit_error(&socket, GameError::TooManyAttempts);
+                return;
+            }
+            let Joined {
+                player,
+                resume_token,
+                game: game_state,
+                settings,
+                code: room_code,
+                chat,
+            } = match call!(game_server, Message::Join, resume_token, passcode).unwrap() {
+                Ok(joined) => joined,
+                Err(error) => {
+                    if let GameError::WrongPasscode = error {
+                        record_wrong_passcode(ip, room_id);
+                    }
+                    emit_error(&socket, error);
+                    return;
+                }
+            };
+            socket.extensions.insert(game_server.clone());
             socket.extensions.insert(player.clone());
             socket.join(game_server.get_name().unwrap()).ok();
             socket
@@ -102,9 +386,14 @@ pub fn setup_socket(socket: SocketRef) {
                     "join",
                     JoinEvent {
                         current_player_id: Some(player.id),
+                        resume_token: Some(resume_token),
+                        room_code: Some(room_code),
                         game_state: Some(game_state.clone()),
+                        settings: Some(settings),
+                        colors: Some(lists::colors()),
                         players: None,
                         spectators: None,
+                        chat: Some(chat),
                     },
                 )
                 .ok();
@@ -114,74 +403,227 @@ pub fn setup_socket(socket: SocketRef) {
                     "join",
                     JoinEvent {
                         current_player_id: None,
+                        resume_token: None,
+                        room_code: None,
                         game_state: None,
+                        settings: None,
+                        colors: None,
                         players: Some(game_state.players()),
-                        spectators: Some(game_state.players()),
+                        spectators: Some(game_state.spectators()),
+                        chat: None,
                     },
                 )
                 .ok();
         },
     );
+    socket.on("watch_rooms", |socket: SocketRef| {
+        if throttled(&socket, "watch_rooms") {
+            return;
+        }
+        socket.join(browser::WATCHERS).ok();
+        socket.emit("rooms", [browser::listings()]).ok();
+    });
     socket.on(
         "change_name",
         |io: SocketIo, socket: SocketRef, Data(name): Data<String>| async move {
+            if throttled(&socket, "change_name") {
+                return;
+            }
             let Some(game_server) = socket.extensions.get::<ActorRef<Message>>() else {
                 return;
             };
             let Some(mut player) = socket.extensions.get::<Player>() else {
                 return;
             };
-            socket.extensions.remove::<Player>();
             player.name = name;
-            socket.extensions.insert(player.clone());
-            let game = call!(game_server, Message::UpdatePlayer, player).unwrap();
+            let (player, game) = call!(game_server, Message::UpdatePlayer, player).unwrap();
+            socket.extensions.insert(player);
             io.to(game_server.get_name().unwrap())
                 .emit(
                     "join",
                     JoinEvent {
                         current_player_id: None,
+                        resume_token: None,
+                        room_code: None,
                         game_state: None,
+                        settings: None,
+                        colors: None,
                         players: Some(game.players()),
                         spectators: Some(game.spectators()),
+                        chat: None,
                     },
                 )
                 .ok();
         },
     );
 
+    socket.on(
+        "update_settings",
+        |io: SocketIo, socket: SocketRef, Data(settings): Data<RoomSettings>| async move {
+            if throttled(&socket, "update_settings") {
+                return;
+            }
+            let Some(game_server) = socket.extensions.get::<ActorRef<Message>>() else {
+                return;
+            };
+            let Some(player) = socket.extensions.get::<Player>() else {
+                return;
+            };
+            match call!(game_server, Message::UpdateSettings, Some(player), settings).unwrap() {
+                Ok(settings) => {
+                    io.to(game_server.get_name().unwrap())
+                        .emit("settings", settings)
+                        .ok();
+                }
+                Err(error) => emit_error(&socket, error),
+            }
+        },
+    );
+
+    socket.on(
+        "submit_words",
+        |io: SocketIo, socket: SocketRef, Data(words): Data<Vec<Word>>| async move {
+            if throttled(&socket, "submit_words") {
+                return;
+            }
+            let Some(game_server) = socket.extensions.get::<ActorRef<Message>>() else {
+                return;
+            };
+            let Some(player) = socket.extensions.get::<Player>() else {
+                return;
+            };
+            match call!(game_server, Message::SubmitWords, player, words).unwrap() {
+                Ok(game) => {
+                    io.to(game_server.get_name().unwrap())
+                        .emit("lobby", game)
+                        .ok();
+                }
+                Err(error) => emit_error(&socket, error),
+            }
+        },
+    );
+
+    socket.on(
+        "change_color",
+        |io: SocketIo, socket: SocketRef, Data(color): Data<String>| async move {
+            if throttled(&socket, "change_color") {
+                return;
+            }
+            let Some(game_server) = socket.extensions.get::<ActorRef<Message>>() else {
+                return;
+            };
+            let Some(player) = socket.extensions.get::<Player>() else {
+                return;
+            };
+            match call!(game_server, Message::ChangeColor, player, color).unwrap() {
+                Ok((player, game)) => {
+                    socket.extensions.insert(player);
+                    io.to(game_server.get_name().unwrap())
+                        .emit(
+                            "join",
+                            JoinEvent {
+                                current_player_id: None,
+                                resume_token: None,
+                                room_code: None,
+                                game_state: None,
+                                settings: None,
+                                colors: None,
+                                players: Some(game.players()),
+                                spectators: Some(game.spectators()),
+                                chat: None,
+                            },
+                        )
+                        .ok();
+                }
+                Err(error) => emit_error(&socket, error),
+            }
+        },
+    );
+
     socket.on("start_game", |io: SocketIo, socket: SocketRef| async move {
+        if throttled(&socket, "start_game") {
+            return;
+        }
         let Some(game_server) = socket.extensions.get::<ActorRef<Message>>() else {
             return;
         };
-        let game = call!(game_server, Message::StartGame).unwrap();
+        match call!(game_server, Message::StartGame).unwrap() {
+            Ok(game) => {
+                io.to(game_server.get_name().unwrap())
+                    .emit("start_game", game)
+                    .ok();
+            }
+            Err(error) => emit_error(&socket, error),
+        }
+    });
+    socket.on("rematch", |io: SocketIo, socket: SocketRef| async move {
+        if throttled(&socket, "rematch") {
+            return;
+        }
+        let Some(game_server) = socket.extensions.get::<ActorRef<Message>>() else {
+            return;
+        };
+        let game = call!(game_server, Message::Rematch).unwrap();
         io.to(game_server.get_name().unwrap())
-            .emit("start_game", game)
+            .emit("rematch", game)
             .ok();
     });
 
     socket.on(
-        "draw",
-        |socket: SocketRef, Data(point): Data<Point>| async move {
+        "draw_start",
+        |socket: SocketRef, Data(event): Data<DrawStartEvent>| async move {
+            if throttled(&socket, "draw_start") {
+                return;
+            }
             let Some(game_server) = socket.extensions.get::<ActorRef<Message>>() else {
                 return;
             };
-            let _ = cast!(game_server, Message::Draw(point.clone()));
-            socket
-                .to(game_server.get_name().unwrap())
-                .emit(
-                    "draw",
-                    DrawEvent {
-                        position: point.clone(),
-                    },
-                )
-                .ok();
+            let Some(player) = socket.extensions.get::<Player>() else {
+                return;
+            };
+            let style = StrokeStyle {
+                width: event.width,
+                color: event.color.unwrap_or_else(|| player.color.clone()),
+                tool: event.tool,
+            };
+            if let Err(error) = call!(game_server, Message::DrawStart, player, style).unwrap() {
+                emit_error(&socket, error);
+            }
         },
     );
+    // Sync so the points are queued with the room before a `draw_end` sent
+    // right after them, which runs in a task of its own, can overtake them
+    socket.on("draw", |socket: SocketRef, Data(draw): Data<DrawIn>| {
+        if throttled(&socket, "draw") {
+            return;
+        }
+        let Some(game_server) = socket.extensions.get::<ActorRef<Message>>() else {
+            return;
+        };
+        let Some(player) = socket.extensions.get::<Player>() else {
+            return;
+        };
+        let Some(points) = draw.into_points() else {
+            emit_error(&socket, GameError::InvalidPoint);
+            return;
+        };
+        let _ = cast!(game_server, Message::Draw(socket.id, player, points));
+    });
     socket.on("draw_end", |io: SocketIo, socket: SocketRef| async move {
+        if throttled(&socket, "draw_end") {
+            return;
+        }
         let Some(game_server) = socket.extensions.get::<ActorRef<Message>>() else {
             return;
         };
-        let (current_player_index, is_last_turn) = call!(game_server, Message::DrawEnd).unwrap();
+        let Some(player) = socket.extensions.get::<Player>() else {
+            return;
+        };
+        let (current_player_index, is_last_turn) =
+            match call!(game_server, Message::DrawEnd, player).unwrap() {
+                Ok(next_turn) => next_turn,
+                Err(error) => return emit_error(&socket, error),
+            };
         io.to(game_server.get_name().unwrap())
             .emit(
                 "next_turn",
@@ -195,6 +637,9 @@ pub fn setup_socket(socket: SocketRef) {
     socket.on(
         "vote_fake",
         |io: SocketIo, socket: SocketRef, Data(event): Data<VoteFakeEvent>| async move {
+            if throttled(&socket, "vote_fake") {
+                return;
+            }
             let Some(game_server) = socket.extensions.get::<ActorRef<Message>>() else {
                 return;
             };
@@ -222,21 +667,73 @@ pub fn setup_socket(socket: SocketRef) {
             }
         },
     );
+    socket.on(
+        "vote_reroll",
+        |io: SocketIo, socket: SocketRef| async move {
+            if throttled(&socket, "vote_reroll") {
+                return;
+            }
+            let Some(game_server) = socket.extensions.get::<ActorRef<Message>>() else {
+                return;
+            };
+            let Some(player) = socket.extensions.get::<Player>() else {
+                return;
+            };
+            match call!(game_server, Message::VoteReroll, player).unwrap() {
+                Ok(Game::InGame(game)) => {
+                    io.to(game_server.get_name().unwrap())
+                        .emit(
+                            "reroll",
+                            RerollEvent {
+                                word: game.word,
+                                reroll_votes: game.reroll_votes,
+                                rerolls_left: game.rerolls_left,
+                            },
+                        )
+                        .ok();
+                }
+                Ok(_) => (),
+                Err(error) => emit_error(&socket, error),
+            }
+        },
+    );
     socket.on(
         "chat_msg",
         |io: SocketIo, socket: SocketRef, Data(msg): Data<String>| async move {
+            if throttled(&socket, "chat_msg") {
+                return;
+            }
             let Some(game_server) = socket.extensions.get::<ActorRef<Message>>() else {
                 return;
             };
             let Some(player) = socket.extensions.get::<Player>() else {
                 return;
             };
-            let _ = cast!(game_server, Message::Chat(player.clone(), msg.clone()));
+            let Some(message) = call!(game_server, Message::Chat, player, msg).unwrap() else {
+                return;
+            };
             io.to(game_server.get_name().unwrap())
-                .emit("chat_msg", ChatMessage::new(player, &msg))
+                .emit("chat_msg", message)
                 .ok();
         },
     );
+    socket.on(
+        "replay",
+        |socket: SocketRef, Data(event): Data<ReplayEvent>| async move {
+            if throttled(&socket, "replay") {
+                return;
+            }
+            let Some(game_server) = socket.extensions.get::<ActorRef<Message>>() else {
+                return;
+            };
+            let game = call!(game_server, Message::GetGame).unwrap();
+            if !matches!(game, Game::GameOver(_)) {
+                emit_error(&socket, GameError::GameNotOver);
+                return;
+            }
+            tokio::spawn(replay(socket, game.curves(), event.speed.unwrap_or(1.0)));
+        },
+    );
     socket.on_disconnect(|socket: SocketRef| async move {
         info!("Socket disconnected: {}", socket.id);
         let Some(game_server) = socket.extensions.get::<ActorRef<Message>>() else {
@@ -245,10 +742,38 @@ pub fn setup_socket(socket: SocketRef) {
         let Some(player) = socket.extensions.get::<Player>() else {
             return;
         };
-        let _ = call!(game_server, Message::Leave, player.clone()).unwrap();
-        socket
-            .to(game_server.get_name().unwrap())
-            .emit("leave", player)
-            .ok();
+        // The room tells everyone else, unless it was closed out from under us
+        let _ = call!(game_server, Message::Leave, player);
     });
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn token_bucket_allows_a_burst_then_refills() {
+        let limit = RateLimit {
+            burst: 3.0,
+            per_second: 2.0,
+        };
+        let start = Instant::now();
+        let mut bucket = TokenBucket::full(limit, start);
+        for _ in 0..3 {
+            assert_eq!(bucket.take(limit, start), Verdict::Allow);
+        }
+        assert_eq!(bucket.take(limit, start), Verdict::Reject);
+        assert_eq!(bucket.take(limit, start), Verdict::RejectQuietly);
+
+        let later = start + Duration::from_millis(500);
+        assert_eq!(bucket.take(limit, later), Verdict::Allow);
+        assert_eq!(bucket.take(limit, later), Verdict::Reject);
+
+        // Refilling stops at the burst size
+        let much_later = later + Duration::from_secs(60);
+        for _ in 0..3 {
+            assert_eq!(bucket.take(limit, much_later), Verdict::Allow);
+        }
+        assert_eq!(bucket.take(limit, much_later), Verdict::Reject);
+    }
+}